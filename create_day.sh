@@ -16,11 +16,43 @@ function okecho() {
   echo >&2 -e "${FMT_RESET}"
 }
 
+function initYear() {
+  local year
+  year="$1"
+  cat <<EOF >"src/aoc${year}.rs"
+use crate::hive;
+
+hive::puzzles! {
+    ${year};
+}
+EOF
+
+  # GEN_YEAR_MOD >> mod aoc2022;
+  # GEN_YEAR_REGISTER >> aoc2022::register(&mut hive);
+  for marker in GEN_YEAR_MOD GEN_YEAR_REGISTER; do
+    if [ "$marker" == "GEN_YEAR_MOD" ]; then
+      ESCAPED_REPLACE="mod aoc${year};"
+    else
+      ESCAPED_REPLACE="aoc${year}::register(\\&mut hive);"
+    fi
+    if [[ "$OSTYPE" == "darwin"* ]] || [[ "$OSTYPE" == "freebsd"* ]]; then
+      sed -i '' -E "s#^(.*)//${marker}#\1${ESCAPED_REPLACE}\n\1//${marker}#g" src/main.rs
+    else
+      sed -i -E "s#^(.*)//${marker}#\1${ESCAPED_REPLACE}\n\1//${marker}#g" src/main.rs
+    fi
+  done
+}
+
 function initDay() {
   local day
   local id
+  local year
   day="$1"
   id=$(printf "%02d" "$day")
+  year="${2:-2022}"
+  if [ ! -r "src/aoc${year}.rs" ]; then
+    initYear "${year}"
+  fi
   if [ -d "day${id}" ]; then
     errecho "day directory already exist"
     return 1
@@ -29,99 +61,48 @@ function initDay() {
     errecho "day file already exist"
     return 1
   fi
-  mkdir -p "src/aoc2022/day${id}"
-  cat <<EOF >"src/aoc2022/day${id}/part1.rs"
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::io;
-use crate::aoc2022::lib::style::{write_header, write_solution};
-
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+  mkdir -p "src/aoc${year}/day${id}" "puzzles/${year}/day${id}"
+  cat <<EOF >"src/aoc${year}/day${id}/part1.rs"
+use anyhow::Result;
 
-fn execute(scope: &PuzzleScope) {
-    let ints = io::read_puzzle_as_ints(scope.day(), "puzzle1");
+use crate::lib::common::{Answer, PuzzleScope};
+use crate::lib::io;
+
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let ints = io::read_puzzle_as_ints(scope, scope.puzzle())?;
     let sum: i32 = ints.iter().sum();
-    write_solution(&scope, format!("sum = {}", sum).as_str());
+    Ok(Answer::from(sum))
 }
 EOF
 
-  cat <<EOF >"src/aoc2022/day${id}/part2.rs"
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::io;
-use crate::aoc2022::lib::style::{write_header, write_solution};
+  cat <<EOF >"src/aoc${year}/day${id}/part2.rs"
+use anyhow::Result;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+use crate::lib::common::{Answer, PuzzleScope};
+use crate::lib::io;
 
-fn execute(scope: &PuzzleScope) {
-    let ints = io::read_puzzle_as_ints(scope.day(), "puzzle2");
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let ints = io::read_puzzle_as_ints(scope, scope.puzzle())?;
     let sum: i32 = ints.iter().sum();
-    write_solution(&scope, format!("sum = {}", sum).as_str());
+    Ok(Answer::from(sum))
 }
 EOF
 
-  cat <<EOF >"src/aoc2022/day${id}.rs"
+  cat <<EOF >"src/aoc${year}/day${id}.rs"
 pub mod part1;
 pub mod part2;
 EOF
 
-  cat <<EOF >>"src/aoc2022.rs"
-pub mod day${id};
-EOF
-
-  # GEN_HIVE_REGISTER >> hive.register(Day::D00, Part::P01, aoc2022::day00::part1::run);
-  ESCAPED_REPLACE=$(printf '%s\n' "hive.register(Day::D${id}, Part::P01, aoc2022::day${id}::part1::run);" | sed -e 's/[\/&]/\\&/g')
-  if [[ "$OSTYPE" == "darwin"* ]] || [[ "$OSTYPE" == "freebsd"* ]]; then
-      sed -i '' -E "s#^(.*)//GEN_HIVE_REGISTER#\1${ESCAPED_REPLACE}\n\1//GEN_HIVE_REGISTER#g" src/main.rs
-    else
-      sed -i -E "s#^(.*)//GEN_HIVE_REGISTER#\1${ESCAPED_REPLACE}\n\1//GEN_HIVE_REGISTER#g" src/main.rs
-    fi
-  ESCAPED_REPLACE=$(printf '%s\n' "hive.register(Day::D${id}, Part::P02, aoc2022::day${id}::part2::run);" | sed -e 's/[\/&]/\\&/g')
-  if [[ "$OSTYPE" == "darwin"* ]] || [[ "$OSTYPE" == "freebsd"* ]]; then
-    sed -i '' -E "s#^(.*)//GEN_HIVE_REGISTER#\1${ESCAPED_REPLACE}\n\1//GEN_HIVE_REGISTER#g" src/main.rs
-  else
-    sed -i -E "s#^(.*)//GEN_HIVE_REGISTER#\1${ESCAPED_REPLACE}\n\1//GEN_HIVE_REGISTER#g" src/main.rs
-  fi
-
-  # GEN_DAY_ITEM >> D01,
-  ESCAPED_REPLACE=$(printf '%s\n' "D${id}," | sed -e 's/[\/&]/\\&/g')
-  if [[ "$OSTYPE" == "darwin"* ]] || [[ "$OSTYPE" == "freebsd"* ]]; then
-    sed -i '' -E "s#^(.*)//GEN_DAY_ITEM#\1${ESCAPED_REPLACE}\n\1//GEN_DAY_ITEM#g" src/aoc2022/hive.rs
-  else
-    sed -i -E "s#^(.*)//GEN_DAY_ITEM#\1${ESCAPED_REPLACE}\n\1//GEN_DAY_ITEM#g" src/aoc2022/hive.rs
-  fi
-
-  # GEN_DAY_STR >> Day::D01 => String::from("d01"),
-  ESCAPED_REPLACE=$(printf '%s\n' "Day::D${id} => String::from(\"d${id}\")," | sed -e 's/[\/&]/\\&/g')
-  if [[ "$OSTYPE" == "darwin"* ]] || [[ "$OSTYPE" == "freebsd"* ]]; then
-    sed -i '' -E "s#^(.*)//GEN_DAY_STR#\1${ESCAPED_REPLACE}\n\1//GEN_DAY_STR#g" src/aoc2022/hive.rs
-  else
-    sed -i -E "s#^(.*)//GEN_DAY_STR#\1${ESCAPED_REPLACE}\n\1//GEN_DAY_STR#g" src/aoc2022/hive.rs
-  fi
-
-  # GEN_DAY_INT >> Day::D01 => 1,
-  ESCAPED_REPLACE=$(printf '%s\n' "Day::D${id} => ${day}," | sed -e 's/[\/&]/\\&/g')
-  if [[ "$OSTYPE" == "darwin"* ]] || [[ "$OSTYPE" == "freebsd"* ]]; then
-    sed -i '' -E "s#^(.*)//GEN_DAY_INT#\1${ESCAPED_REPLACE}\n\1//GEN_DAY_INT#g" src/aoc2022/hive.rs
-  else
-    sed -i -E "s#^(.*)//GEN_DAY_INT#\1${ESCAPED_REPLACE}\n\1//GEN_DAY_INT#g" src/aoc2022/hive.rs
-  fi
-
-  # GEN_DAY_PARSE >> "d01" => Ok(Day::D01),
-  ESCAPED_REPLACE=$(printf '%s\n' "\"d${id}\" => Ok(Day::D${id})," | sed -e 's/[\/&]/\\&/g')
+  # registers the day as last entry of `puzzles!`, which is the only line being a bare `}`
   if [[ "$OSTYPE" == "darwin"* ]] || [[ "$OSTYPE" == "freebsd"* ]]; then
-    sed -i '' -E "s#^(.*)//GEN_DAY_PARSE#\1${ESCAPED_REPLACE}\n\1//GEN_DAY_PARSE#g" src/aoc2022/hive.rs
+    sed -i '' -E "s#^}\$#    ${day} => day${id} [1 => part1, 2 => part2],\n}#" "src/aoc${year}.rs"
   else
-    sed -i -E "s#^(.*)//GEN_DAY_PARSE#\1${ESCAPED_REPLACE}\n\1//GEN_DAY_PARSE#g" src/aoc2022/hive.rs
+    sed -i -E "s#^}\$#    ${day} => day${id} [1 => part1, 2 => part2],\n}#" "src/aoc${year}.rs"
   fi
 
 }
 
-if ! initDay "$1"; then
+if ! initDay "$1" "$2"; then
   errecho "Failed initializing day"
   exit 1
 fi
\ No newline at end of file
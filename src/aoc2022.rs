@@ -1,28 +1,31 @@
-pub mod lib;
-pub mod hive;
-pub mod day00;
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day20;
-pub mod day21;
-pub mod day22;
-pub mod day23;
-pub mod day24;
-pub mod day25;
+use crate::hive;
+
+hive::puzzles! {
+    2022;
+    0 => day00 [1 => part1, 2 => part2],
+    1 => day01 [1 => part1, 2 => part2],
+    2 => day02 [1 => part1, 2 => part2],
+    3 => day03 [1 => part1, 2 => part2],
+    4 => day04 [1 => part1, 2 => part2],
+    5 => day05 [1 => part1, 2 => part2],
+    6 => day06 [1 => part1, 2 => part2],
+    7 => day07 [1 => part1, 2 => part2],
+    8 => day08 [1 => part1, 2 => part2],
+    9 => day09 [1 => part1, 2 => part2],
+    10 => day10 [1 => part1, 2 => part2],
+    11 => day11 [1 => part1, 2 => part2],
+    12 => day12 [1 => part1, 2 => part2],
+    13 => day13 [1 => part1, 2 => part2],
+    14 => day14 [1 => part1, 2 => part2],
+    15 => day15 [1 => part1, 2 => part2],
+    16 => day16 [1 => part1, 2 => part2],
+    17 => day17 [1 => part1, 2 => part2],
+    18 => day18 [1 => part1, 2 => part2],
+    19 => day19 [1 => part1, 2 => part2],
+    20 => day20 [1 => part1, 2 => part2],
+    21 => day21 [1 => part1, 2 => part2],
+    22 => day22 [1 => part1, 2 => part2],
+    23 => day23 [1 => part1, 2 => part2],
+    24 => day24 [1 => part1, 2 => part2],
+    25 => day25 [1 => part1],
+}
@@ -1,14 +1,10 @@
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::io;
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use anyhow::Result;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+use crate::lib::common::{Answer, PuzzleScope};
+use crate::lib::io;
 
-fn execute(scope: &PuzzleScope) {
-    let ints = io::read_puzzle_as_ints(scope.day(), "puzzle1");
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let ints = io::read_puzzle_as_ints(scope, scope.puzzle())?;
     let sum: i32 = ints.iter().sum();
-    write_solution(&scope, format!("sum = {}", sum).as_str());
+    Ok(Answer::from(sum))
 }
@@ -1,24 +1,20 @@
 use std::collections::HashMap;
 
-use crate::aoc2022::lib;
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use anyhow::Result;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+use crate::lib;
+use crate::lib::common::{Answer, PuzzleScope};
 
-fn execute(scope: &PuzzleScope) {
-    let map: HashMap<i32, i32> = build_carries(scope, "puzzle1");
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let map: HashMap<i32, i32> = build_carries(scope, scope.puzzle())?;
     let max = map.values().max().unwrap();
-    write_solution(&scope, format!("max calories = {}", max).as_str());
+    Ok(Answer::from(*max))
 }
 
-pub fn build_carries(scope: &PuzzleScope, puzzle: &str) -> HashMap<i32, i32> {
+pub fn build_carries(scope: &PuzzleScope, puzzle: &str) -> Result<HashMap<i32, i32>> {
     let mut map: HashMap<i32, i32> = HashMap::new();
     let mut count = 0;
-    for v in lib::io::read_puzzle_as_list(scope.day(), puzzle) {
+    for v in lib::io::read_puzzle_as_list(scope, puzzle)? {
         if v.len() == 0 {
             count += 1;
         } else {
@@ -28,5 +24,5 @@ pub fn build_carries(scope: &PuzzleScope, puzzle: &str) -> HashMap<i32, i32> {
                 .or_insert(num);
         }
     }
-    map
+    Ok(map)
 }
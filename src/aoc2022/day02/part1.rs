@@ -1,28 +1,24 @@
+use anyhow::Result;
+
 use crate::aoc2022::day02::rps::RPS;
 use crate::aoc2022::day02::rps::Winning;
-use crate::aoc2022::lib;
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::style::{write_header, write_solution};
-
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+use crate::lib;
+use crate::lib::common::{Answer, PuzzleScope};
 
-fn execute(scope: &PuzzleScope) {
-    let score: i32 = play(scope, "puzzle1");
-    write_solution(&scope, format!("score = {}", score).as_str());
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let score: i32 = play(scope, scope.puzzle())?;
+    Ok(Answer::from(score))
 }
 
-fn play(scope: &PuzzleScope, puzzle: &str) -> i32 {
+fn play(scope: &PuzzleScope, puzzle: &str) -> Result<i32> {
     let mut score: i32 = 0;
-    for v in lib::io::read_puzzle_as_list(scope.day(), puzzle) {
+    for v in lib::io::read_puzzle_as_list(scope, puzzle)? {
         let opponent_select = RPS::decode(v.chars().nth(0).unwrap());
         let player_select = RPS::decode(v.chars().nth(2).unwrap());
         score += score_round(opponent_select, player_select);
     }
 
-    score
+    Ok(score)
 }
 
 pub fn score_round(opponent_select: RPS, player_select: RPS) -> i32 {
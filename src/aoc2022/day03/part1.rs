@@ -1,22 +1,18 @@
 use std::collections::HashSet;
 
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::io;
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use anyhow::Result;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+use crate::lib::common::{Answer, PuzzleScope};
+use crate::lib::io;
 
-fn execute(scope: &PuzzleScope) {
-    let sum: i32 = resolve_priority_sum(scope, "puzzle1");
-    write_solution(&scope, format!("sum = {}", sum).as_str());
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let sum: i32 = resolve_priority_sum(scope, scope.puzzle())?;
+    Ok(Answer::from(sum))
 }
 
-fn resolve_priority_sum(scope: &PuzzleScope, puzzle: &str) -> i32 {
+fn resolve_priority_sum(scope: &PuzzleScope, puzzle: &str) -> Result<i32> {
     let mut sum = 0;
-    for line in io::read_puzzle_as_list(scope.day(), puzzle) {
+    for line in io::read_puzzle_as_list(scope, puzzle)? {
         let compartment_size = line.len() / 2;
         let compartment1: HashSet<_> = line.chars()
             .take(compartment_size)
@@ -31,7 +27,7 @@ fn resolve_priority_sum(scope: &PuzzleScope, puzzle: &str) -> i32 {
         let priority = decode_item(ch);
         sum += priority;
     }
-    sum
+    Ok(sum)
 }
 
 pub fn decode_item(ch: char) -> i32 {
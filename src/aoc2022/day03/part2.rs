@@ -1,24 +1,20 @@
 use std::collections::HashSet;
 
-use crate::aoc2022::day03::part1::decode_item;
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::io;
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use anyhow::Result;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+use crate::aoc2022::day03::part1::decode_item;
+use crate::lib::common::{Answer, PuzzleScope};
+use crate::lib::io;
 
-fn execute(scope: &PuzzleScope) {
-    let sum: i32 = resolve_priority_sum(scope, "puzzle1");
-    write_solution(&scope, format!("sum = {}", sum).as_str());
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let sum: i32 = resolve_priority_sum(scope, scope.puzzle())?;
+    Ok(Answer::from(sum))
 }
 
-fn resolve_priority_sum(scope: &PuzzleScope, puzzle: &str) -> i32 {
+fn resolve_priority_sum(scope: &PuzzleScope, puzzle: &str) -> Result<i32> {
     let mut sum = 0;
 
-    let lines = io::read_puzzle_as_list(scope.day(), puzzle);
+    let lines = io::read_puzzle_as_list(scope, puzzle)?;
     let mut groups: Vec<Vec<String>> = Vec::new();
     for g_offset in 0..(lines.len() / 3) {
         let mut group: Vec<String> = Vec::new();
@@ -38,7 +34,7 @@ fn resolve_priority_sum(scope: &PuzzleScope, puzzle: &str) -> i32 {
         let priority = decode_item(ch);
         sum += priority;
     }
-    sum
+    Ok(sum)
 }
 
 pub fn multi_intersection(collections: &Vec<HashSet<char>>) -> HashSet<char> {
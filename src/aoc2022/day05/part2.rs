@@ -1,22 +1,18 @@
 use std::collections::HashMap;
 
-use crate::aoc2022::day05::part1::{get_stacks_top, parse_input};
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::io;
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use anyhow::Result;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+use crate::aoc2022::day05::part1::{get_stacks_top, parse_input};
+use crate::lib::common::{Answer, PuzzleScope};
+use crate::lib::io;
 
-fn execute(scope: &PuzzleScope) {
-    let result = execute_puzzle(&scope, "puzzle1");
-    write_solution(&scope, format!("top = {}", result).as_str());
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let result = execute_puzzle(scope, scope.puzzle())?;
+    Ok(Answer::from(result))
 }
 
-fn execute_puzzle(scope: &PuzzleScope, puzzle: &str) -> String {
-    let (stacks, instructions) = parse_input(io::read_puzzle_as_list(scope.day(), puzzle));
+fn execute_puzzle(scope: &PuzzleScope, puzzle: &str) -> Result<String> {
+    let (stacks, instructions) = parse_input(io::read_puzzle_as_list(scope, puzzle)?);
 
     let mut stack_map: HashMap<i32, Vec<char>> = HashMap::new();
     for stack in stacks {
@@ -38,5 +34,5 @@ fn execute_puzzle(scope: &PuzzleScope, puzzle: &str) -> String {
         }
     }
 
-    get_stacks_top(stack_map)
+    Ok(get_stacks_top(stack_map))
 }
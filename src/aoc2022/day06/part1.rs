@@ -1,25 +1,21 @@
 use std::collections::HashSet;
 
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::io;
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use anyhow::Result;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+use crate::lib::common::{Answer, PuzzleScope};
+use crate::lib::io;
 
-fn execute(scope: &PuzzleScope) {
-    let signal = find_signal(scope, "puzzle1", 4);
-    write_solution(&scope, format!("signal = {}", signal).as_str());
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let signal = find_signal(scope, scope.puzzle(), 4)?;
+    Ok(Answer::from(signal))
 }
 
-pub fn find_signal(scope: &PuzzleScope, puzzle: &str, length: usize) -> i32 {
-    let input = io::read_puzzle_as_string(scope.day(), puzzle);
+pub fn find_signal(scope: &PuzzleScope, puzzle: &str, length: usize) -> Result<i32> {
+    let input = io::read_puzzle_as_string(scope, puzzle)?;
     for i in length..input.len() {
         if input[(i - length)..i].chars().collect::<HashSet<_>>().len() == length {
-            return i as i32;
+            return Ok(i as i32);
         }
     }
-    -1
+    Ok(-1)
 }
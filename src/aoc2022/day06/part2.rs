@@ -1,13 +1,9 @@
-use crate::aoc2022::day06::part1::find_signal;
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use anyhow::Result;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+use crate::aoc2022::day06::part1::find_signal;
+use crate::lib::common::{Answer, PuzzleScope};
 
-fn execute(scope: &PuzzleScope) {
-    let signal = find_signal(scope, "puzzle1", 14);
-    write_solution(&scope, format!("signal = {}", signal).as_str());
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let signal = find_signal(scope, scope.puzzle(), 14)?;
+    Ok(Answer::from(signal))
 }
@@ -1,3 +1,6 @@
 pub mod part1;
 pub mod part2;
+#[allow(dead_code)]
 mod fs;
+#[allow(dead_code)]
+mod shell;
@@ -1,4 +1,5 @@
 use std::borrow::Borrow;
+use std::cell::Cell;
 use std::collections::HashMap;
 
 pub type Fd = i32;
@@ -62,7 +63,7 @@ impl Filesystem {
         self.data.get_mut(&fd)
     }
 
-    pub fn _ls(&self, context: Fd) -> Vec<&File> {
+    pub fn ls(&self, context: Fd) -> Vec<&File> {
         if let Some(dir) = self.get(context) {
             dir.children.iter()
                 .map(|f| {
@@ -82,29 +83,122 @@ impl Filesystem {
         }
     }
 
+    // A directory remembers its size until something below it changes. Computing a size fills
+    // the caches of all directories below, so an empty cache means all parents are empty, too.
     fn size_total_0(&self, file: &File) -> i32 {
         if file.is_dir {
+            if let Some(size) = file.cached_size.get() {
+                return size;
+            }
             let mut result = 0;
-            for fd in file.children.clone() {
-                if let Some(f) = self.get(fd) {
-                    result = result + self.size_total_0(f);
+            for fd in &file.children {
+                if let Some(f) = self.get(*fd) {
+                    result += self.size_total_0(f);
                 }
             }
+            file.cached_size.set(Some(result));
             result
         } else {
             file.size
         }
     }
 
+    // the sizes of all directories at once, in the same order as `each`
+    pub fn directory_sizes(&self) -> Vec<(Fd, i32)> {
+        let mut result = vec!();
+        let mut stack: Vec<Fd> = vec![self.root];
+        while let Some(fd) = stack.pop() {
+            let f = self.get(fd).unwrap();
+            if f.is_dir {
+                result.push((fd, self.size_total_0(f)));
+                stack.extend(f.children.iter().rev());
+            }
+        }
+        result
+    }
+
+    // the directory and all above have to be computed again
+    fn invalidate(&self, fd: Fd) {
+        let mut current = Some(fd);
+        while let Some(f) = current.and_then(|fd| self.get(fd)) {
+            if f.cached_size.take().is_none() {
+                break;
+            }
+            current = f.parent;
+        }
+    }
+
     fn append(&mut self, context: Fd, item: File) {
         let item_fd = item.borrow().fd;
         self.data.insert(item.fd, item);
         self.get_mut(context).unwrap().append(item_fd);
+        self.invalidate(context);
     }
 
     fn _remove(&mut self, context: Fd, item: Fd) {
         self.get_mut(context).unwrap()._remove(item.clone());
         self.data.remove(&item);
+        self.invalidate(context);
+    }
+
+    // removes a file or a directory with everything in it, the root stays
+    pub fn remove(&mut self, fd: Fd) -> bool {
+        let parent = match self.parent(fd) {
+            Some(parent) => parent,
+            None => return false,
+        };
+        self.get_mut(parent).unwrap()._remove(fd);
+        self.invalidate(parent);
+        let mut stack = vec![fd];
+        while let Some(fd) = stack.pop() {
+            if let Some(f) = self.data.remove(&fd) {
+                stack.extend(f.children);
+            }
+        }
+        true
+    }
+
+    // into the directory `target` as `name`, a directory cannot go into itself
+    pub fn move_to(&mut self, fd: Fd, target: Fd, name: &str) -> bool {
+        let parent = match self.parent(fd) {
+            Some(parent) => parent,
+            None => return false,
+        };
+        if self.is_within(target, fd) {
+            return false;
+        }
+        self.get_mut(parent).unwrap()._remove(fd);
+        self.get_mut(target).unwrap().append(fd);
+        self.invalidate(parent);
+        self.invalidate(target);
+        let file = self.get_mut(fd).unwrap();
+        file.parent = Some(target);
+        file.name = name.to_string();
+        true
+    }
+
+    // `fd` is `dir` or somewhere below it
+    pub fn is_within(&self, fd: Fd, dir: Fd) -> bool {
+        let mut current = Some(fd);
+        while let Some(c) = current {
+            if c == dir {
+                return true;
+            }
+            current = self.parent(c);
+        }
+        false
+    }
+
+    // absolute like `/a/e`
+    pub fn path(&self, fd: Fd) -> String {
+        let mut names = vec!();
+        let mut current = fd;
+        while let Some(parent) = self.parent(current) {
+            names.push(self.get(current).unwrap().name.clone());
+            current = parent;
+        }
+        names.reverse();
+        format!("/{}", names.join("/"))
     }
 
     pub fn mkdir(&mut self, context: Fd, name: &str) -> Fd {
@@ -173,6 +267,8 @@ pub struct File {
     is_dir: bool,
     children: Vec<Fd>,
     size: i32,
+    // the total of a directory, see `Filesystem::size_total_0`
+    cached_size: Cell<Option<i32>>,
 }
 
 impl File {
@@ -184,6 +280,7 @@ impl File {
             is_dir: true,
             children: vec!(),
             size: 0,
+            cached_size: Cell::new(None),
         }
     }
 
@@ -195,6 +292,7 @@ impl File {
             is_dir: false,
             children: vec!(),
             size: size,
+            cached_size: Cell::new(None),
         }
     }
 
@@ -206,11 +304,12 @@ impl File {
             is_dir: true,
             children: vec!(),
             size: 0,
+            cached_size: Cell::new(None),
         }
     }
 
 
-    pub fn _name(&self) -> &str {
+    pub fn name(&self) -> &str {
         &self.name
     }
 
@@ -253,16 +352,37 @@ fn fs_demo() {
     let mut fs = Filesystem::new();
     let root = fs.root();
     println!("FS DEMO");
-    println!("{}", fs.get(root).unwrap()._name());
+    println!("{}", fs.get(root).unwrap().name());
     let demo = fs.mkdir(root, "demo");
     fs.mkdir(demo, "x");
     fs.add_file(demo, "abc", 123);
     fs.add_file(demo, "abc1", 126);
     println!("ls");
-    for d in fs._ls(demo) {
+    for d in fs.ls(demo) {
         println!("{}", d.name);
     }
     println!("size = {}", fs.size_total(root).unwrap());
     fs._remove(root, demo);
     println!("size = {}", fs.size_total(root).unwrap());
 }
+
+#[test]
+fn fs_cached_sizes() {
+    let mut fs = Filesystem::new();
+    let root = fs.root();
+    let a = fs.mkdir(root, "a");
+    let b = fs.mkdir(a, "b");
+    fs.add_file(b, "x", 10);
+    assert_eq!(fs.directory_sizes(), vec!((root, 10), (a, 10), (b, 10)));
+
+    let y = fs.add_file(a, "y", 5);
+    assert_eq!(fs.size_total(root), Some(15));
+    assert_eq!(fs.size_total(b), Some(10));
+    let c = fs.mkdir(root, "c");
+    fs.move_to(b, c, "b");
+    assert_eq!(fs.directory_sizes(), vec!((root, 15), (a, 5), (c, 10), (b, 10)));
+    fs._remove(a, y);
+    assert_eq!(fs.size_total(a), Some(0));
+    fs.remove(b);
+    assert_eq!(fs.directory_sizes(), vec!((root, 0), (a, 0), (c, 0)));
+}
@@ -0,0 +1,353 @@
+use anyhow::{anyhow, bail, Context, Result};
+
+use crate::aoc2022::day07::fs::{Fd, File, Filesystem};
+
+// A working directory on top of a `Filesystem`. Paths are absolute like `/a/e` or relative to
+// the working directory, `.` and `..` included.
+pub struct Shell {
+    fs: Filesystem,
+    cwd: Fd,
+}
+
+impl Default for Shell {
+    fn default() -> Self {
+        Shell::new()
+    }
+}
+
+impl Shell {
+    pub fn new() -> Self {
+        let fs = Filesystem::new();
+        let cwd = fs.root();
+        Shell { fs, cwd }
+    }
+
+    pub fn fs(&self) -> &Filesystem {
+        &self.fs
+    }
+
+    pub fn into_fs(self) -> Filesystem {
+        self.fs
+    }
+
+    pub fn cwd(&self) -> Fd {
+        self.cwd
+    }
+
+    pub fn pwd(&self) -> String {
+        self.fs.path(self.cwd)
+    }
+
+    pub fn resolve(&self, path: &str) -> Result<Fd> {
+        let mut current = if path.starts_with('/') { self.fs.root() } else { self.cwd };
+        for name in path.split('/').filter(|n| !n.is_empty()) {
+            current = match name {
+                "." => current,
+                // like a real shell, the parent of the root is the root
+                ".." => self.fs.parent(current).unwrap_or(current),
+                _ => {
+                    if !self.fs.get(current).unwrap().is_dir() {
+                        bail!("{}: not a directory", self.fs.path(current));
+                    }
+                    self.fs.find(current, name.to_string())
+                        .ok_or_else(|| anyhow!("{}: no such file or directory", path))?
+                }
+            }
+        }
+        Ok(current)
+    }
+
+    pub fn cd(&mut self, path: &str) -> Result<()> {
+        let fd = self.resolve(path)?;
+        if !self.fs.get(fd).unwrap().is_dir() {
+            bail!("{}: not a directory", path);
+        }
+        self.cwd = fd;
+        Ok(())
+    }
+
+    pub fn ls(&self, path: &str) -> Result<Vec<&File>> {
+        let fd = self.resolve(path)?;
+        match self.fs.get(fd) {
+            Some(f) if f.is_dir() => Ok(self.fs.ls(fd)),
+            Some(f) => Ok(vec![f]),
+            None => bail!("{}: no such file or directory", path),
+        }
+    }
+
+    pub fn mkdir(&mut self, path: &str) -> Result<Fd> {
+        let (parent, name) = self.prepare_new(path)?;
+        Ok(self.fs.mkdir(parent, name))
+    }
+
+    pub fn touch(&mut self, path: &str, size: i32) -> Result<Fd> {
+        let (parent, name) = self.prepare_new(path)?;
+        Ok(self.fs.add_file(parent, name, size))
+    }
+
+    // always recursive, the working directory moves up if it is gone
+    pub fn rm(&mut self, path: &str) -> Result<()> {
+        let fd = self.resolve(path)?;
+        if fd == self.fs.root() {
+            bail!("{}: cannot remove the root", path);
+        }
+        if self.fs.is_within(self.cwd, fd) {
+            self.cwd = self.fs.parent(fd).unwrap();
+        }
+        self.fs.remove(fd);
+        Ok(())
+    }
+
+    // into `to` if that is a directory, otherwise renamed to `to`
+    pub fn mv(&mut self, from: &str, to: &str) -> Result<()> {
+        let fd = self.resolve(from)?;
+        let (target, name) = match self.resolve(to) {
+            Ok(target) if self.fs.get(target).unwrap().is_dir() => {
+                let name = self.fs.get(fd).unwrap().name().to_string();
+                if self.fs.find(target, name.clone()).is_some() {
+                    bail!("{}/{}: already exists", to.trim_end_matches('/'), name);
+                }
+                (target, name)
+            }
+            Ok(_) => bail!("{}: already exists", to),
+            Err(_) => {
+                let (target, name) = self.prepare_new(to)?;
+                (target, name.to_string())
+            }
+        };
+        if !self.fs.move_to(fd, target, &name) {
+            bail!("cannot move {} into {}", from, to);
+        }
+        Ok(())
+    }
+
+    // `du`: every directory below `path` with its total size, the deepest first
+    pub fn du(&self, path: &str) -> Result<Vec<(String, i32)>> {
+        let fd = self.resolve(path)?;
+        let mut result = vec!();
+        self.du_0(fd, &mut result);
+        Ok(result)
+    }
+
+    fn du_0(&self, fd: Fd, result: &mut Vec<(String, i32)>) {
+        let file = self.fs.get(fd).unwrap();
+        if !file.is_dir() {
+            return;
+        }
+        for child in file.children() {
+            self.du_0(child, result);
+        }
+        result.push((self.fs.path(fd), self.fs.size_total(fd).unwrap()));
+    }
+
+    // the paths of everything below `path` whose name matches the glob, `*` and `?` only
+    pub fn find(&self, path: &str, pattern: &str) -> Result<Vec<String>> {
+        let start = self.resolve(path)?;
+        let mut result = vec!();
+        let mut stack = vec![start];
+        while let Some(fd) = stack.pop() {
+            let file = self.fs.get(fd).unwrap();
+            if glob_matches(pattern, file.name()) {
+                result.push(self.fs.path(fd));
+            }
+            stack.extend(file.children().into_iter().rev());
+        }
+        Ok(result)
+    }
+
+    // like the listing of the puzzle, but with the sizes of the directories
+    pub fn tree(&self, path: &str) -> Result<String> {
+        let start = self.resolve(path)?;
+        let mut result = String::new();
+        let mut stack = vec![(start, 0)];
+        while let Some((fd, indent)) = stack.pop() {
+            let file = self.fs.get(fd).unwrap();
+            let kind = if file.is_dir() { "dir" } else { "file" };
+            let size = self.fs.size_total(fd).unwrap();
+            result += &format!("{}- {} ({}, size={})\n", "  ".repeat(indent), file.name(), kind, size);
+            stack.extend(file.children().into_iter().rev().map(|c| (c, indent + 1)));
+        }
+        Ok(result)
+    }
+
+    // The commands of a transcript with the output of every `ls`, like the puzzle input.
+    // `mkdir`, `touch <size>`, `rm` and `mv` are understood as well.
+    pub fn replay(transcript: &str) -> Result<Shell> {
+        let mut shell = Shell::new();
+        let mut listing = false;
+        for (idx, line) in transcript.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+            let result = match line.strip_prefix("$ ") {
+                Some(command) => {
+                    listing = command == "ls";
+                    shell.execute(command)
+                }
+                None if listing => shell.add_listed(line),
+                None => Err(anyhow!("output without a command")),
+            };
+            result.with_context(|| format!("line {}: {}", idx + 1, line))?;
+        }
+        Ok(shell)
+    }
+
+    fn execute(&mut self, command: &str) -> Result<()> {
+        let args: Vec<&str> = command.split_whitespace().collect();
+        match args[..] {
+            ["cd", path] => self.cd(path),
+            // the output follows on the next lines
+            ["ls"] => Ok(()),
+            ["mkdir", path] => self.mkdir(path).map(|_| ()),
+            ["touch", size, path] => {
+                let size = size.parse().with_context(|| format!("invalid size '{}'", size))?;
+                self.touch(path, size).map(|_| ())
+            }
+            ["rm", path] => self.rm(path),
+            ["mv", from, to] => self.mv(from, to),
+            _ => bail!("unknown command"),
+        }
+    }
+
+    // an `ls` may be run twice in the same directory
+    fn add_listed(&mut self, line: &str) -> Result<()> {
+        let (info, name) = line.split_once(' ').ok_or_else(|| anyhow!("expected '<size|dir> <name>'"))?;
+        if self.fs.find(self.cwd, name.to_string()).is_some() {
+            return Ok(());
+        }
+        if info == "dir" {
+            self.fs.mkdir(self.cwd, name);
+        } else {
+            let size = info.parse().with_context(|| format!("invalid size '{}'", info))?;
+            self.fs.add_file(self.cwd, name, size);
+        }
+        Ok(())
+    }
+
+    // The transcript which `replay` turns into the same tree again.
+    pub fn transcript(&self) -> String {
+        let mut result = String::from("$ cd /\n");
+        self.transcript_0(self.fs.root(), &mut result);
+        result
+    }
+
+    fn transcript_0(&self, fd: Fd, result: &mut String) {
+        *result += "$ ls\n";
+        let entries = self.fs.ls(fd);
+        for f in &entries {
+            if f.is_dir() {
+                *result += &format!("dir {}\n", f.name());
+            } else {
+                *result += &format!("{} {}\n", f.size(), f.name());
+            }
+        }
+        for f in entries.iter().filter(|f| f.is_dir()) {
+            *result += &format!("$ cd {}\n", f.name());
+            self.transcript_0(self.fs.find(fd, f.name().to_string()).unwrap(), result);
+            *result += "$ cd ..\n";
+        }
+    }
+
+    // parent directory and name of something to be created
+    fn prepare_new<'a>(&self, path: &'a str) -> Result<(Fd, &'a str)> {
+        let path = path.trim_end_matches('/');
+        let (dir, name) = match path.rsplit_once('/') {
+            Some(("", name)) => ("/", name),
+            Some((dir, name)) => (dir, name),
+            None => (".", path),
+        };
+        if name.is_empty() || name == "." || name == ".." {
+            bail!("{}: invalid name", path);
+        }
+        let parent = self.resolve(dir)?;
+        if !self.fs.get(parent).unwrap().is_dir() {
+            bail!("{}: not a directory", dir);
+        }
+        if self.fs.find(parent, name.to_string()).is_some() {
+            bail!("{}: already exists", path);
+        }
+        Ok((parent, name))
+    }
+}
+
+fn glob_matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    // matches[j]: the pattern so far matches the first j chars of the name
+    let mut matches = vec![false; name.len() + 1];
+    matches[0] = true;
+    for p in pattern {
+        let mut next = vec![false; name.len() + 1];
+        for j in 0..=name.len() {
+            next[j] = match p {
+                '*' => matches[j] || (j > 0 && next[j - 1]),
+                '?' => j > 0 && matches[j - 1],
+                c => j > 0 && matches[j - 1] && name[j - 1] == c,
+            };
+        }
+        matches = next;
+    }
+    matches[name.len()]
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "$ cd /\n$ ls\ndir a\n14848514 b.txt\n8504156 c.dat\ndir d\n$ cd a\n$ ls\ndir e\n29116 f\n2557 g\n62596 h.lst\n$ cd e\n$ ls\n584 i\n$ cd ..\n$ cd ..\n$ cd d\n$ ls\n4060174 j\n8033020 d.log\n5626152 d.ext\n7214296 k\n";
+
+#[test]
+fn shell_paths() {
+    let mut shell = Shell::replay(EXAMPLE).unwrap();
+    assert_eq!(shell.pwd(), "/d");
+    shell.cd("/a/e").unwrap();
+    assert_eq!(shell.pwd(), "/a/e");
+    shell.cd("../../d/./").unwrap();
+    assert_eq!(shell.pwd(), "/d");
+    shell.cd("/").unwrap();
+    assert_eq!(shell.cwd(), shell.fs().root());
+    assert!(shell.cd("b.txt").is_err());
+    assert!(shell.cd("x").is_err());
+    assert_eq!(shell.du("/").unwrap(), vec!(
+        ("/a/e".to_string(), 584),
+        ("/a".to_string(), 94853),
+        ("/d".to_string(), 24933642),
+        ("/".to_string(), 48381165),
+    ));
+    assert_eq!(shell.find("/", "*.???").unwrap(), vec!("/a/h.lst", "/b.txt", "/c.dat", "/d/d.log", "/d/d.ext"));
+    assert_eq!(shell.find("a", "?").unwrap(), vec!("/a", "/a/e", "/a/e/i", "/a/f", "/a/g"));
+}
+
+#[test]
+fn shell_changes() {
+    let mut shell = Shell::replay(EXAMPLE).unwrap();
+    shell.mv("/a/e", "/d").unwrap();
+    shell.mv("/d/e/i", "/d/e/i.txt").unwrap();
+    assert!(shell.mv("/d", "/d/e").is_err());
+    assert!(shell.mv("/b.txt", "/c.dat").is_err());
+    shell.rm("/a").unwrap();
+    assert!(shell.resolve("/a/f").is_err());
+    shell.mkdir("/x").unwrap();
+    shell.cd("/").unwrap();
+    shell.touch("x/y", 10).unwrap();
+    assert!(shell.mkdir("/x/y").is_err());
+    assert!(shell.touch("/b.txt/z", 1).is_err());
+    assert_eq!(shell.tree("/d/e").unwrap(), "- e (dir, size=584)\n  - i.txt (file, size=584)\n");
+
+    // rm moves out of a removed working directory
+    shell.cd("/d/e").unwrap();
+    shell.rm("/d").unwrap();
+    assert_eq!(shell.pwd(), "/");
+    assert_eq!(shell.du("/").unwrap(), vec!(("/x".to_string(), 10), ("/".to_string(), 23352680)));
+}
+
+#[test]
+fn shell_transcript() {
+    let shell = Shell::replay(EXAMPLE).unwrap();
+    let again = Shell::replay(&shell.transcript()).unwrap();
+    assert_eq!(again.tree("/").unwrap(), shell.tree("/").unwrap());
+    assert_eq!(again.transcript(), shell.transcript());
+
+    let shell = Shell::replay("$ cd /\n$ mkdir a\n$ touch 5 a/b\n$ mv a c\n$ ls\n").unwrap();
+    assert_eq!(shell.transcript(), "$ cd /\n$ ls\ndir c\n$ cd c\n$ ls\n5 b\n$ cd ..\n");
+
+    let err = Shell::replay("$ cd /\n$ cd a\n").err().unwrap();
+    assert!(format!("{:#}", err).starts_with("line 2: $ cd a: a: no such file"), "{:#}", err);
+}
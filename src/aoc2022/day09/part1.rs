@@ -1,15 +1,11 @@
 use std::collections::HashSet;
-use crate::aoc2022::lib::common::{measure_time_and_print, parse_int, PuzzleScope};
-use crate::aoc2022::lib::io;
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use anyhow::Result;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+use crate::lib::common::{Answer, parse_int, PuzzleScope};
+use crate::lib::io;
 
-fn execute(scope: &PuzzleScope) {
-    let instructions = parse_input(scope, "puzzle1");
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let instructions = parse_input(scope, scope.puzzle())?;
 
     let mut visited: HashSet<(i32,i32)> = HashSet::new();
     let start = (0, 0);
@@ -68,11 +64,11 @@ fn execute(scope: &PuzzleScope) {
         }
     }
 
-    write_solution(&scope, format!("sum = {}", visited.len()).as_str());
+    Ok(Answer::from(visited.len()))
 }
 
-pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Vec<(char, i32)> {
-    io::read_puzzle_as_list(scope.day(), puzzle)
+pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Result<Vec<(char, i32)>> {
+    Ok(io::read_puzzle_as_list(scope, puzzle)?
         .iter()
         .map(|line| {
             let split: Vec<&str> = line.split_whitespace().collect();
@@ -80,6 +76,6 @@ pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Vec<(char, i32)> {
             let i: i32 = parse_int(split.get(1).unwrap());
             (ch, i)
         })
-        .collect()
+        .collect())
 }
 
@@ -1,16 +1,12 @@
 use std::collections::HashSet;
 
-use crate::aoc2022::day09::part1::parse_input;
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use anyhow::Result;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+use crate::aoc2022::day09::part1::parse_input;
+use crate::lib::common::{Answer, PuzzleScope};
 
-fn execute(scope: &PuzzleScope) {
-    let instructions = parse_input(scope, "puzzle1");
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let instructions = parse_input(scope, scope.puzzle())?;
 
     let mut visited: HashSet<(i32, i32)> = HashSet::new();
     let start = (0, 0);
@@ -87,5 +83,5 @@ fn execute(scope: &PuzzleScope) {
         }
     }
 
-    write_solution(&scope, format!("sum = {}", visited.len()).as_str());
+    Ok(Answer::from(visited.len()))
 }
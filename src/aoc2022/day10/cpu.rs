@@ -0,0 +1,190 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use anyhow::{bail, Result};
+
+pub struct Instruction {
+    name: String,
+    args: Vec<i32>,
+}
+
+impl Instruction {
+    pub fn new(name: String, args: Vec<i32>) -> Self {
+        Instruction {
+            name,
+            args,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn args(&self) -> &[i32] {
+        &self.args
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        for arg in &self.args {
+            write!(f, " {}", arg)?;
+        }
+        Ok(())
+    }
+}
+
+// Named registers, unknown ones are 0.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Registers {
+    values: BTreeMap<char, i32>,
+}
+
+impl Registers {
+    pub fn get(&self, name: char) -> i32 {
+        self.values.get(&name).copied().unwrap_or(0)
+    }
+
+    pub fn set(&mut self, name: char, value: i32) {
+        self.values.insert(name, value);
+    }
+
+    pub fn add(&mut self, name: char, value: i32) {
+        self.set(name, self.get(name) + value);
+    }
+}
+
+impl Display for Registers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let values: Vec<String> = self.values.iter().map(|(name, v)| format!("{}={}", name, v)).collect();
+        write!(f, "{}", values.join(" "))
+    }
+}
+
+// An entry of the instruction table. The effect applies at the end of the last cycle.
+pub struct Op {
+    pub name: &'static str,
+    pub cycles: usize,
+    pub args: usize,
+    pub effect: fn(&mut Registers, &[i32]),
+}
+
+// the instruction set of the handheld
+pub const HANDHELD_OPS: &[Op] = &[
+    Op { name: "noop", cycles: 1, args: 0, effect: |_, _| {} },
+    Op { name: "addx", cycles: 2, args: 1, effect: |r, args| r.add('x', args[0]) },
+];
+
+// What an observer sees during a cycle, before the effect of an instruction ending in it.
+pub struct Tick<'a> {
+    // starting with 1
+    pub cycle: usize,
+    pub instruction: &'a Instruction,
+    // the cycle of the instruction, starting with 1
+    pub step: usize,
+    pub registers: &'a Registers,
+}
+
+pub trait Observer {
+    fn during(&mut self, tick: &Tick);
+}
+
+impl<F: FnMut(&Tick)> Observer for F {
+    fn during(&mut self, tick: &Tick) {
+        self(tick)
+    }
+}
+
+pub struct Cpu {
+    ops: &'static [Op],
+    registers: Registers,
+    cycle: usize,
+    trace: bool,
+}
+
+impl Cpu {
+    pub fn create(ops: &'static [Op]) -> Cpu {
+        Cpu {
+            ops,
+            registers: Registers::default(),
+            cycle: 0,
+            trace: false,
+        }
+    }
+
+    // the CPU of the handheld starts with x = 1
+    pub fn handheld() -> Cpu {
+        Cpu::create(HANDHELD_OPS).with_register('x', 1)
+    }
+
+    pub fn with_register(mut self, name: char, value: i32) -> Cpu {
+        self.registers.set(name, value);
+        self
+    }
+
+    // prints every cycle
+    pub fn trace(mut self, enabled: bool) -> Cpu {
+        self.trace = enabled;
+        self
+    }
+
+    pub fn registers(&self) -> &Registers {
+        &self.registers
+    }
+
+    // the number of cycles completed so far
+    pub fn cycle(&self) -> usize {
+        self.cycle
+    }
+
+    pub fn run(&mut self, program: &[Instruction], observers: &mut [&mut dyn Observer]) -> Result<()> {
+        for (idx, instruction) in program.iter().enumerate() {
+            let op = match self.ops.iter().find(|op| op.name == instruction.name()) {
+                Some(op) => op,
+                None => bail!("instruction {}: unknown '{}'", idx + 1, instruction),
+            };
+            if instruction.args().len() != op.args {
+                bail!("instruction {}: '{}' expects {} arguments", idx + 1, instruction, op.args);
+            }
+            for step in 1..=op.cycles {
+                self.cycle += 1;
+                let tick = Tick { cycle: self.cycle, instruction, step, registers: &self.registers };
+                if self.trace {
+                    println!("cycle {:>4}: {:<10} ({}/{}) {}", tick.cycle, instruction.to_string(), step, op.cycles, self.registers);
+                }
+                for observer in observers.iter_mut() {
+                    observer.during(&tick);
+                }
+            }
+            (op.effect)(&mut self.registers, instruction.args());
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn cpu_small_program() {
+    let program = vec!(
+        Instruction::new("noop".to_string(), vec!()),
+        Instruction::new("addx".to_string(), vec!(3)),
+        Instruction::new("addx".to_string(), vec!(-5)),
+    );
+    let mut cpu = Cpu::handheld();
+    let mut xs = vec!();
+    let mut steps = vec!();
+    cpu.run(&program, &mut [
+        &mut |tick: &Tick| xs.push(tick.registers.get('x')),
+        &mut |tick: &Tick| steps.push((tick.instruction.name().to_string(), tick.step)),
+    ]).unwrap();
+    assert_eq!(xs, vec!(1, 1, 1, 4, 4));
+    assert_eq!(steps[2], ("addx".to_string(), 2));
+    assert_eq!(cpu.cycle(), 5);
+    assert_eq!(cpu.registers().get('x'), -1);
+
+    let mut cpu = Cpu::handheld();
+    let err = cpu.run(&[Instruction::new("jmp".to_string(), vec!(1))], &mut []).unwrap_err();
+    assert_eq!(err.to_string(), "instruction 1: unknown 'jmp 1'");
+    let err = cpu.run(&[Instruction::new("addx".to_string(), vec!())], &mut []).unwrap_err();
+    assert_eq!(err.to_string(), "instruction 1: 'addx' expects 1 arguments");
+}
@@ -1,86 +1,38 @@
-use std::collections::HashMap;
+use anyhow::Result;
+use itertools::Itertools;
 
+use crate::aoc2022::day10::cpu::{Cpu, Tick};
 use crate::aoc2022::day10::part1::parse_input;
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::style::{write_header, write_solution};
-
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
-
-fn execute(scope: &PuzzleScope) {
-    let instructions = parse_input(scope, "puzzle1");
-
-    let mut pc = 1;
-    let mut register_x = 1;
-    let mut last_instruction: Option<usize> = None;
-    let mut active_instruction: Option<(usize, i32)> = None;
-
-    let mut crt: HashMap<i32, bool> = HashMap::new();
-
-    loop {
-        if let None = active_instruction {
-            let next_instruction_idx = if let Some(last) = last_instruction {
-                last + 1
-            } else {
-                // init
-                0
-            };
-            if next_instruction_idx >= instructions.len() {
-                break;
-            }
-            let next_instruction = instructions.get(next_instruction_idx).unwrap();
-            let next_instruction_cycles = match next_instruction.name() {
-                "noop" => 1,
-                "addx" => 2,
-                _ => 0,
-            };
-            active_instruction = Some((next_instruction_idx, next_instruction_cycles));
-            //println!("Start cycle {}: begin executing {} {}", pc, next_instruction.name(), next_instruction.value());
-        }
-
-        if let Some((instruction_idx, cycles)) = active_instruction {
-
-            //println!("Cycle {}", pc);
-            let position_in_row = (pc % 40) - 1;
-            crt.insert(pc, [register_x - 1, register_x, register_x + 1].contains(&position_in_row));
-
-            //println!("During cycle {}: CRT draws pixel at position {}", pc, position_in_row);
-
-            if cycles > 1 {
-                active_instruction = Some((instruction_idx, cycles - 1));
-            } else {
-                let instruction = instructions.get(instruction_idx).unwrap();
-                //println!("Processing {} {}", instruction.name(), instruction.value());
-                match instruction.name() {
-                    "addx" => {
-                        register_x = register_x + instruction.value();
-                    }
-                    _ => ()
-                };
-                active_instruction = None;
-                last_instruction = Some(instruction_idx);
-            }
+use crate::lib::common::{Answer, PuzzleScope};
+use crate::lib::grid::Grid2D;
+use crate::lib::ocr;
+
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let instructions = parse_input(scope, scope.puzzle())?;
+
+    let mut cpu = Cpu::handheld().trace(scope.param("trace").unwrap_or(false));
+    let mut crt: Vec<bool> = vec!();
+    cpu.run(&instructions, &mut [&mut |tick: &Tick| {
+        // the sprite is 3 pixels wide, centered at x
+        let position_in_row = ((tick.cycle - 1) % 40) as i32;
+        crt.push((tick.registers.get('x') - position_in_row).abs() <= 1);
+    }])?;
+
+    let mut screen = Grid2D::create(crt.len() as i32 / 40, 40, false);
+    for (i, &lit) in crt.iter().enumerate() {
+        screen.set(i as i32 % 40, i as i32 / 40, lit);
+    }
+    let art = screen.rows()
+        .map(|row| row.iter().map(|&lit| if lit { '#' } else { '.' }).collect::<String>())
+        .join("\n");
+    println!("{}", art);
+
+    // the example draws no letters
+    match ocr::recognize(&screen) {
+        Ok(text) => Ok(Answer::from(text)),
+        Err(err) => {
+            println!("Cannot read the screen: {err:#}");
+            Ok(Answer::from(art))
         }
-        pc = pc + 1;
     }
-
-    let result_list: Vec<String> = (1..pc).into_iter()
-        .map(|i| {
-            let ch = match crt.get(&i) {
-                Some(true) => '#',
-                _ => '.'
-            };
-            if i % 40 == 0 {
-                String::from(ch) + "\n"
-            } else {
-                String::from(ch)
-            }
-        })
-        .collect();
-    let result = result_list.join("");
-
-    // PAPJCBHP
-    write_solution(&scope, format!("\n{}", result.as_str()).as_str());
-}
\ No newline at end of file
+}
@@ -1,2 +1,5 @@
 pub mod part1;
 pub mod part2;
+#[allow(dead_code)]
+mod events;
+mod expr;
@@ -0,0 +1,183 @@
+use crate::aoc2022::day11::expr::{Expr, Worry};
+use crate::aoc2022::day11::part1::Game;
+
+// why a worry level changed
+pub enum Cause<'a> {
+    Operation(&'a Expr),
+    // divided by the value, as the monkey gets bored
+    Relief(usize),
+}
+
+// What happens during `play_game`, in order.
+pub enum Event<'a, W> {
+    TurnStarted { monkey: usize },
+    ItemInspected { monkey: usize, level: &'a W },
+    WorryChanged { monkey: usize, cause: Cause<'a>, level: &'a W },
+    ItemThrown { from: usize, to: usize, level: &'a W, divisible: bool, test_div: usize },
+    RoundFinished { round: usize, game: &'a Game<W> },
+}
+
+pub trait Listener<W> {
+    fn on(&mut self, event: &Event<W>);
+}
+
+impl<W, F: FnMut(&Event<W>)> Listener<W> for F {
+    fn on(&mut self, event: &Event<W>) {
+        self(event)
+    }
+}
+
+pub fn emit<W>(listeners: &mut [&mut dyn Listener<W>], event: Event<W>) {
+    for listener in listeners.iter_mut() {
+        listener.on(&event);
+    }
+}
+
+// Prints the game like the example does. A zero `*_every` never prints that part.
+pub struct Logger {
+    pub steps: bool,
+    pub summary_every: usize,
+    pub inspections_every: usize,
+}
+
+impl Logger {
+    pub fn format<W: Worry>(&self, event: &Event<W>) -> Vec<String> {
+        match event {
+            Event::RoundFinished { round, game } => self.format_round(*round, game),
+            _ if !self.steps => vec!(),
+            Event::TurnStarted { monkey } => vec!(format!("Monkey {}:", monkey)),
+            Event::ItemInspected { level, .. } => {
+                vec!(format!("  Monkey inspects an item with a worry level of {}.", level))
+            }
+            Event::WorryChanged { cause: Cause::Operation(operation), level, .. } => {
+                vec!(format!("    Worry level {} to {}.", describe(operation), level))
+            }
+            Event::WorryChanged { cause: Cause::Relief(div_by), level, .. } => {
+                vec!(format!("    Monkey gets bored with item. Worry level is divided by {} to {}.", div_by, level))
+            }
+            Event::ItemThrown { to, level, divisible, test_div, .. } => vec!(
+                format!("    Current worry level is {}divisible by {}.", if *divisible { "" } else { "not " }, test_div),
+                format!("    Item with worry level {} is thrown to monkey {}.", level, to),
+            ),
+        }
+    }
+
+    fn format_round<W: Worry>(&self, round: usize, game: &Game<W>) -> Vec<String> {
+        let summary = is_nth(round, self.summary_every);
+        let inspections = is_nth(round, self.inspections_every);
+        let mut lines = vec!();
+        if !summary && !inspections {
+            return lines;
+        }
+        lines.push(String::new());
+        lines.push(format!("== After round {} ==", round));
+        if summary {
+            for monkey_id in 0..game.monkeys.len() {
+                let item_str_list: Vec<String> = game.items[&monkey_id]
+                    .iter()
+                    .map(|d| d.to_string())
+                    .collect();
+                lines.push(format!("Monkey {}: {}", monkey_id, item_str_list.join(", ")));
+            }
+        }
+        if inspections {
+            for monkey_id in 0..game.monkeys.len() {
+                lines.push(format!("Monkey {} inspected items {} times.", monkey_id, game.inspections[&monkey_id]));
+            }
+        }
+        lines.push(String::new());
+        lines
+    }
+}
+
+impl<W: Worry> Listener<W> for Logger {
+    fn on(&mut self, event: &Event<W>) {
+        for line in self.format(event) {
+            println!("{}", line);
+        }
+    }
+}
+
+fn is_nth(round: usize, every: usize) -> bool {
+    every > 0 && round.is_multiple_of(every)
+}
+
+// like the example transcript does
+fn describe(operation: &Expr) -> String {
+    match operation {
+        Expr::Mul(a, b) if **a == Expr::Old && **b == Expr::Old => "is multiplied by itself".to_string(),
+        Expr::Add(a, b) if **a == Expr::Old && **b == Expr::Old => "increases by itself".to_string(),
+        Expr::Mul(a, b) if **a == Expr::Old => format!("is multiplied by {}", b),
+        Expr::Add(a, b) if **a == Expr::Old => format!("increases by {}", b),
+        _ => format!("becomes {}", operation),
+    }
+}
+
+#[test]
+fn events_throws_of_first_round() {
+    use crate::aoc2022::day11::part1::{example_game, play_game};
+
+    let mut game: Game = example_game();
+    let mut throws = vec!();
+    let mut rounds = vec!();
+    play_game(&mut game, 1, 3, 1, &mut [&mut |event: &Event<usize>| match event {
+        Event::ItemThrown { from, to, level, .. } => throws.push((*from, **level, *to)),
+        Event::RoundFinished { round, .. } => rounds.push(*round),
+        _ => {}
+    }]).unwrap();
+    assert_eq!(throws, vec!(
+        (0, 500, 3), (0, 620, 3),
+        (1, 20, 0), (1, 23, 0), (1, 27, 0), (1, 26, 0),
+        (2, 2080, 1), (2, 1200, 3), (2, 3136, 3),
+        (3, 25, 1), (3, 167, 1), (3, 207, 1), (3, 401, 1), (3, 1046, 1),
+    ));
+    assert_eq!(rounds, vec!(1));
+}
+
+#[test]
+fn events_logged_like_the_example() {
+    use crate::aoc2022::day11::part1::{example_game, play_game};
+
+    let mut game: Game = example_game();
+    let logger = Logger { steps: true, summary_every: 1, inspections_every: 0 };
+    let mut lines = vec!();
+    play_game(&mut game, 1, 3, 1, &mut [&mut |event: &Event<usize>| lines.extend(logger.format(event))]).unwrap();
+    let expected = [
+        "Monkey 0:",
+        "  Monkey inspects an item with a worry level of 79.",
+        "    Worry level is multiplied by 19 to 1501.",
+        "    Monkey gets bored with item. Worry level is divided by 3 to 500.",
+        "    Current worry level is not divisible by 23.",
+        "    Item with worry level 500 is thrown to monkey 3.",
+    ];
+    assert_eq!(lines[..6], expected);
+    assert!(lines.contains(&"    Worry level is multiplied by itself to 6241.".to_string()));
+    assert_eq!(lines[lines.len() - 6..], [
+        "== After round 1 ==",
+        "Monkey 0: 20, 23, 27, 26",
+        "Monkey 1: 2080, 25, 167, 207, 401, 1046",
+        "Monkey 2: ",
+        "Monkey 3: ",
+        "",
+    ]);
+}
+
+#[test]
+fn events_relief_only_when_applied() {
+    use crate::aoc2022::day11::part1::{example_game, play_game};
+
+    let mut causes = vec!();
+    let mut listener = |event: &Event<usize>| if let Event::WorryChanged { cause, .. } = event {
+        causes.push(match cause {
+            Cause::Operation(_) => "operation",
+            Cause::Relief(_) => "relief",
+        });
+    };
+    let mut small: Game = example_game();
+    play_game(&mut small, 1, 1, 96577, &mut [&mut listener]).unwrap();
+    let mut plain: Game = example_game();
+    play_game(&mut plain, 1, 1, 1, &mut [&mut listener]).unwrap();
+    // one operation per inspection, and nothing else
+    let inspections: usize = small.inspections.values().chain(plain.inspections.values()).sum();
+    assert_eq!(causes, vec!("operation"; inspections));
+}
@@ -0,0 +1,225 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Error, Result};
+use num_bigint::BigUint;
+
+
+// A worry level: `usize` is enough as long as the levels are kept small with a modulus,
+// `BigUint` shows what happens without.
+pub trait Worry: Clone + Display + PartialOrd {
+    fn from_usize(v: usize) -> Self;
+    fn plus(&self, other: &Self) -> Self;
+    // None below zero
+    fn minus(&self, other: &Self) -> Option<Self>;
+    fn times(&self, other: &Self) -> Self;
+    fn div_by(&self, divisor: usize) -> Self;
+    fn rem_by(&self, divisor: usize) -> usize;
+}
+
+impl Worry for usize {
+    fn from_usize(v: usize) -> Self {
+        v
+    }
+    fn plus(&self, other: &Self) -> Self {
+        self + other
+    }
+    fn minus(&self, other: &Self) -> Option<Self> {
+        self.checked_sub(*other)
+    }
+    fn times(&self, other: &Self) -> Self {
+        self * other
+    }
+    fn div_by(&self, divisor: usize) -> Self {
+        self / divisor
+    }
+    fn rem_by(&self, divisor: usize) -> usize {
+        self % divisor
+    }
+}
+
+impl Worry for BigUint {
+    fn from_usize(v: usize) -> Self {
+        BigUint::from(v)
+    }
+    fn plus(&self, other: &Self) -> Self {
+        self + other
+    }
+    fn minus(&self, other: &Self) -> Option<Self> {
+        if self < other { None } else { Some(self - other) }
+    }
+    fn times(&self, other: &Self) -> Self {
+        self * other
+    }
+    fn div_by(&self, divisor: usize) -> Self {
+        self / divisor
+    }
+    fn rem_by(&self, divisor: usize) -> usize {
+        usize::try_from(self % divisor).unwrap()
+    }
+}
+
+// The right side of `new = old * 3 + 2`, with `+`, `-`, `*` and parentheses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expr {
+    Old,
+    Value(usize),
+    Add(Box<Expr>, Box<Expr>),
+    Sub(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    // with a `modulus` of more than 1 every step is reduced by it, so `old - 7` still works on reduced levels
+    pub fn eval<W: Worry>(&self, old: &W, modulus: usize) -> Result<W> {
+        let (a, b) = match self {
+            Expr::Old | Expr::Value(_) if modulus > 1 => return Ok(W::from_usize(self.eval(old, 1)?.rem_by(modulus))),
+            Expr::Old => return Ok(old.clone()),
+            Expr::Value(v) => return Ok(W::from_usize(*v)),
+            Expr::Add(a, b) | Expr::Sub(a, b) | Expr::Mul(a, b) => (a.eval(old, modulus)?, b.eval(old, modulus)?),
+        };
+        if modulus > 1 {
+            let (a, b) = (a.rem_by(modulus), b.rem_by(modulus));
+            return Ok(W::from_usize(match self {
+                Expr::Add(..) => (a + b) % modulus,
+                Expr::Sub(..) => (a + modulus - b) % modulus,
+                _ => a * b % modulus,
+            }));
+        }
+        Ok(match self {
+            Expr::Add(..) => a.plus(&b),
+            Expr::Sub(..) => a.minus(&b).ok_or_else(|| anyhow!("{} - {} is below zero", a, b))?,
+            _ => a.times(&b),
+        })
+    }
+
+    fn precedence(&self) -> usize {
+        match self {
+            Expr::Add(..) | Expr::Sub(..) => 1,
+            Expr::Mul(..) => 2,
+            _ => 3,
+        }
+    }
+
+    // parentheses only where the precedence needs them
+    fn fmt_operand(&self, f: &mut Formatter<'_>, min: usize) -> std::fmt::Result {
+        if self.precedence() < min {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (a, op, b) = match self {
+            Expr::Old => return write!(f, "old"),
+            Expr::Value(v) => return write!(f, "{}", v),
+            Expr::Add(a, b) => (a, "+", b),
+            Expr::Sub(a, b) => (a, "-", b),
+            Expr::Mul(a, b) => (a, "*", b),
+        };
+        // left-associative, so the right side needs parentheses on the same level
+        a.fmt_operand(f, self.precedence())?;
+        write!(f, " {} ", op)?;
+        b.fmt_operand(f, self.precedence() + 1)
+    }
+}
+
+impl FromStr for Expr {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut parser = Parser { text: s, pos: 0 };
+        let expr = parser.expr()?;
+        match parser.peek() {
+            None => Ok(expr),
+            Some(c) => bail!("{}: unexpected '{}'", parser.pos + 1, c),
+        }
+    }
+}
+
+// recursive descent, `pos` is the byte offset of the next char
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&mut self) -> Option<char> {
+        let rest = &self.text[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+        self.text[self.pos..].chars().next()
+    }
+
+    fn expr(&mut self) -> Result<Expr> {
+        let mut result = self.term()?;
+        while let Some(c @ ('+' | '-')) = self.peek() {
+            self.pos += 1;
+            let right = Box::new(self.term()?);
+            result = if c == '+' { Expr::Add(Box::new(result), right) } else { Expr::Sub(Box::new(result), right) };
+        }
+        Ok(result)
+    }
+
+    fn term(&mut self) -> Result<Expr> {
+        let mut result = self.factor()?;
+        while let Some('*') = self.peek() {
+            self.pos += 1;
+            result = Expr::Mul(Box::new(result), Box::new(self.factor()?));
+        }
+        Ok(result)
+    }
+
+    fn factor(&mut self) -> Result<Expr> {
+        let next = self.peek();
+        let col = self.pos + 1;
+        match next {
+            Some('(') => {
+                self.pos += 1;
+                let result = self.expr()?;
+                match self.peek() {
+                    Some(')') => {
+                        self.pos += 1;
+                        Ok(result)
+                    }
+                    _ => bail!("{}: missing ')' for '(' at {}", self.pos + 1, col),
+                }
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let rest = &self.text[self.pos..];
+                let len = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+                let value = rest[..len].parse()
+                    .map_err(|e| anyhow!("{}: invalid number '{}': {}", self.pos + 1, &rest[..len], e))?;
+                self.pos += len;
+                Ok(Expr::Value(value))
+            }
+            Some(_) if self.text[self.pos..].starts_with("old") => {
+                self.pos += 3;
+                Ok(Expr::Old)
+            }
+            Some(c) => bail!("{}: expected 'old', a number or '(', got '{}'", self.pos + 1, c),
+            None => bail!("{}: expected 'old', a number or '(', got the end", self.pos + 1),
+        }
+    }
+}
+
+#[test]
+fn expr_parse_and_eval() {
+    let expr: Expr = "old * 3 + 2".parse().unwrap();
+    assert_eq!(expr.eval(&5usize, 1).unwrap(), 17);
+    let expr: Expr = "(old - 1) * (old+ 1)".parse().unwrap();
+    assert_eq!(expr.to_string(), "(old - 1) * (old + 1)");
+    assert_eq!(expr.eval(&7usize, 1).unwrap(), 48);
+    assert_eq!("old - (2 - 1) - 1".parse::<Expr>().unwrap().to_string(), "old - (2 - 1) - 1");
+    assert!("old - 10".parse::<Expr>().unwrap().eval(&5usize, 1).is_err());
+    // -5 and 8 * 9 - 3 modulo 7
+    assert_eq!("old - 10".parse::<Expr>().unwrap().eval(&5usize, 7).unwrap(), 2);
+    assert_eq!("old * (old + 1) - 3".parse::<Expr>().unwrap().eval(&8usize, 7).unwrap(), 6);
+    assert_eq!("old".parse::<Expr>().unwrap().eval(&100usize, 7).unwrap(), 2);
+
+    assert_eq!("old * ".parse::<Expr>().unwrap_err().to_string(), "7: expected 'old', a number or '(', got the end");
+    assert_eq!("(old + 1".parse::<Expr>().unwrap_err().to_string(), "9: missing ')' for '(' at 1");
+    assert_eq!("old old".parse::<Expr>().unwrap_err().to_string(), "5: unexpected 'o'");
+}
@@ -1,234 +1,121 @@
+use std::cmp::Reverse;
 use std::collections::HashMap;
 
-use crate::aoc2022::lib::common::{measure_time_and_print, parse_int, PuzzleScope};
-use crate::aoc2022::lib::io;
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use anyhow::{bail, Context, Result};
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+use crate::aoc2022::day11::events::{Cause, emit, Event, Listener, Logger};
+use crate::aoc2022::day11::expr::{Expr, Worry};
+use crate::lib::common::{Answer, PuzzleScope};
+use crate::lib::io;
 
-fn execute(scope: &PuzzleScope) {
-    let mut game = Game {
-        monkeys: parse_input(scope, "puzzle1"),
-        items: HashMap::new(),
-        inspections: HashMap::new(),
-    };
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let mut game: Game = Game::new(parse_input(scope, scope.puzzle())?);
 
     let rounds = 20;
 
-    play_game(&mut game, rounds, true, 1, 1, 3, 1);
+    let mut logger = Logger { steps: true, summary_every: 1, inspections_every: 1 };
+    play_game(&mut game, rounds, 3, 1, &mut [&mut logger])?;
     println!();
 
-    let mut mm: Vec<(usize, usize)> = vec!();
-    game.inspections.iter().for_each(|(monkey_id, count)| mm.push((*count, *monkey_id)));
-    mm.sort_by(|a, b| b.0.cmp(&a.0));
-    let result = mm.get(0).unwrap().0 * mm.get(1).unwrap().0;
-
-    write_solution(&scope, format!("result = {}", result).as_str());
+    Ok(Answer::from(game.monkey_business()))
 }
 
-pub struct Game {
+pub struct Game<W = usize> {
     pub monkeys: Vec<Monkey>,
-    pub items: HashMap<usize, Vec<usize>>,
+    pub items: HashMap<usize, Vec<W>>,
     pub inspections: HashMap<usize, usize>,
 }
 
-pub fn play_game(game: &mut Game, rounds: usize, print_steps: bool, print_summary_on_nth: usize, print_inspections_on_nth: usize, div_by: usize, mod_by: usize) {
+impl<W> Game<W> {
+    pub fn new(monkeys: Vec<Monkey>) -> Self {
+        Game { monkeys, items: HashMap::new(), inspections: HashMap::new() }
+    }
+
+    // the product of the two highest inspection counts
+    pub fn monkey_business(&self) -> usize {
+        let mut counts: Vec<usize> = self.inspections.values().copied().collect();
+        counts.sort_by_key(|&n| Reverse(n));
+        counts[0] * counts[1]
+    }
+}
+
+pub fn play_game<W: Worry>(game: &mut Game<W>, rounds: usize, div_by: usize, mod_by: usize, listeners: &mut [&mut dyn Listener<W>]) -> Result<()> {
     for monkey_id in 0..game.monkeys.len() {
         let monkey = game.monkeys.get(monkey_id).unwrap();
-        game.items.insert(monkey_id, monkey.start_items().clone());
+        game.items.insert(monkey_id, monkey.start_items().iter().map(|&v| W::from_usize(v)).collect());
+        game.inspections.insert(monkey_id, 0);
     }
 
     for round in 1..=rounds {
         for monkey_id in 0..game.monkeys.len() {
             let monkey = game.monkeys.get(monkey_id).unwrap();
-            if print_steps {
-                println!("Monkey {}:", monkey_id);
-            }
-            let items = game.items.get(&monkey_id).unwrap().clone();
-            game.items.insert(monkey_id, vec!()); // clear
+            emit(listeners, Event::TurnStarted { monkey: monkey_id });
+            let items = std::mem::take(game.items.get_mut(&monkey_id).unwrap());
             for item in items {
-                if print_steps {
-                    println!("  Monkey inspects an item with a worry level of {}.", item);
+                emit(listeners, Event::ItemInspected { monkey: monkey_id, level: &item });
+                *game.inspections.get_mut(&monkey_id).unwrap() += 1;
+
+                // the operation is the only place reducing by the modulus
+                let mut level = monkey.operation().eval(&item, mod_by)?;
+                emit(listeners, Event::WorryChanged { monkey: monkey_id, cause: Cause::Operation(monkey.operation()), level: &level });
+                if div_by > 1 && level > W::from_usize(div_by) {
+                    level = level.div_by(div_by); // REVISIT
+                    emit(listeners, Event::WorryChanged { monkey: monkey_id, cause: Cause::Relief(div_by), level: &level });
                 }
-                game.inspections.entry(monkey_id)
-                    .and_modify(|c| *c = *c + 1)
-                    .or_insert(1);
-                let mut level = item;
-                level = match monkey.operation() {
-                    Operation::MultiplyOld => {
-                        let result = level * level;
-                        if print_steps {
-                            println!("    Worry level is multiplied by itself to {}.", result);
-                        }
-                        result
-                    }
-                    Operation::AddOld => {
-                        let result = level + level;
-                        if print_steps {
-                            println!("    Worry level increases by itself to {}.", result);
-                        }
-                        result
-                    }
-                    Operation::MultiplyValue(v) => {
-                        let result = level * v;
-                        if print_steps {
-                            println!("    Worry level is multiplied by {} to {}.", v, result);
-                        }
-                        result
-                    }
-                    Operation::AddValue(v) => {
-                        let result = level + v;
-                        if print_steps {
-                            println!("    Worry level increases by {} to {}.", v, result);
-                        }
-                        result
-                    }
-                };
-                if div_by > 1 && level > div_by {
-                    level = level / div_by; // REVISIT
-                }
-                if mod_by > 1 {
-                    level = level % mod_by;
-                }
-                if print_steps {
-                    println!("    Monkey gets bored with item. Worry level is divided by {} to {}.", div_by, level);
-                }
-                if level % monkey.test_div() == 0 {
-                    if print_steps {
-                        println!("    Current worry level is divisible by {}", monkey.test_div());
-                    }
-                    let other = game.items.get_mut(&monkey.test_if_true()).unwrap();
-                    other.push(level);
-                    if print_steps {
-                        println!("    Item with worry level {} is thrown to monkey {}.", level, monkey.test_if_true());
-                    }
-                } else {
-                    if print_steps {
-                        println!("    Current worry level is not divisible by {}", monkey.test_div);
-                    }
-                    let other = game.items.get_mut(&monkey.test_if_false()).unwrap();
-                    other.push(level);
-                    if print_steps {
-                        println!("    Item with worry level {} is thrown to monkey {}.", level, monkey.test_if_false());
-                    }
-                }
-            }
-        }
 
-        if round % print_summary_on_nth == 0 || round % print_inspections_on_nth == 0 {
-            println!();
-            println!("== After round {} ==", round);
-            if round % print_summary_on_nth == 0 {
-                for monkey_id in 0..game.monkeys.len() {
-                    let item_str_list: Vec<String> = game.items.get(&monkey_id)
-                        .unwrap()
-                        .iter()
-                        .map(|d| d.to_string())
-                        .collect();
-                    println!("Monkey {}: {}", monkey_id, item_str_list.join(", "));
-                }
-            }
-            if round % print_inspections_on_nth == 0 {
-                for monkey_id in 0..game.monkeys.len() {
-                    let count = game.inspections.get(&monkey_id).unwrap();
-                    println!("Monkey {} inspected items {} times.", monkey_id, count);
-                }
+                let divisible = level.rem_by(monkey.test_div()) == 0;
+                let to = if divisible { monkey.test_if_true() } else { monkey.test_if_false() };
+                emit(listeners, Event::ItemThrown { from: monkey_id, to, level: &level, divisible, test_div: monkey.test_div() });
+                game.items.get_mut(&to).unwrap().push(level);
             }
-            println!();
         }
+        emit(listeners, Event::RoundFinished { round, game });
     }
+    Ok(())
 }
 
-pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Vec<Monkey> {
+pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Result<Vec<Monkey>> {
     let mut result: Vec<Monkey> = vec!();
-
-    let mut m_items: Vec<usize> = vec!();
-    let mut m_operation: Operation = Operation::MultiplyOld;
-    let mut m_test: usize = 1;
-    let mut m_test_true: usize = 0;
-    let mut m_test_false: usize = 1;
-    for line in io::read_puzzle_as_list(scope.day(), puzzle) {
-        if line.starts_with("Monkey") {
-            // skip
-        } else if line.contains("Starting") {
-            let mut split: Vec<&str> = line.split(": ").collect();
-            split = split.get(1).unwrap().split(", ").collect();
-            m_items = split.iter()
-                .map(|s| parse_int(s) as usize)
-                .collect();
-        } else if line.contains("Operation") {
-            let split: Vec<&str> = line.split(": new = old ").collect();
-            let temp = split.get(1).unwrap();
-            let parts: Vec<&str> = temp.split(" ").collect();
-            match parts.get(1).unwrap().to_string().as_str() {
-                "old" => {
-                    match parts.get(0).unwrap().to_string().as_str() {
-                        "+" => m_operation = Operation::AddOld,
-                        "*" => m_operation = Operation::MultiplyOld,
-                        _ => ()
-                    }
-                }
-                expr2 => {
-                    let v = parse_int(expr2) as usize;
-                    match parts.get(0).unwrap().to_string().as_str() {
-                        "+" => m_operation = Operation::AddValue(v),
-                        "*" => m_operation = Operation::MultiplyValue(v),
-                        _ => ()
-                    }
-                }
-            }
-        } else if line.contains("Test: ") {
-            let split: Vec<&str> = line.split("Test: divisible by ").collect();
-            m_test = parse_int(split.get(1).unwrap()) as usize;
-        } else if line.contains("If true") {
-            let split: Vec<&str> = line.split("throw to monkey ").collect();
-            m_test_true = parse_int(split.get(1).unwrap()) as usize;
-        } else if line.contains("If false") {
-            let split: Vec<&str> = line.split("throw to monkey ").collect();
-            m_test_false = parse_int(split.get(1).unwrap()) as usize;
-        } else if line.is_empty() {
-            let monkey = Monkey::new(
-                m_items,
-                m_operation,
-                m_test,
-                m_test_true,
-                m_test_false,
-            );
-            result.push(monkey);
-            m_items = vec!();
-            m_operation = Operation::AddOld;
-            m_test = 0;
-            m_test_true = 0;
-            m_test_false = 0;
-        }
+    for block in io::read_puzzle_as_blocks(scope, puzzle)? {
+        let [_, items, operation, test, if_true, if_false] = &block[..] else {
+            bail!("expected 6 lines per monkey, got {}", block.len());
+        };
+        let monkey = Monkey::new(
+            io::ints_in(items)?,
+            parse_operation(operation)?,
+            single_int(test)?,
+            single_int(if_true)?,
+            single_int(if_false)?,
+        );
+        result.push(monkey);
     }
+    Ok(result)
+}
 
-    // last
-    let monkey = Monkey::new(
-        m_items,
-        m_operation,
-        m_test,
-        m_test_true,
-        m_test_false,
-    );
-    result.push(monkey);
+fn single_int(line: &str) -> Result<usize> {
+    match io::ints_in(line)?[..] {
+        [v] => Ok(v),
+        _ => bail!("expected one number in '{}'", line),
+    }
+}
 
-    result
+fn parse_operation(line: &str) -> Result<Expr> {
+    let Some((_, expr)) = line.split_once("new = ") else {
+        bail!("invalid operation '{}'", line);
+    };
+    expr.parse().with_context(|| format!("invalid operation '{}'", line))
 }
 
 pub struct Monkey {
     start_items: Vec<usize>,
-    operation: Operation,
+    operation: Expr,
     test_div: usize,
     test_if_true: usize,
     test_if_false: usize,
 }
 
 impl Monkey {
-    pub fn new(start_items: Vec<usize>, operation: Operation, test_div: usize, test_if_true: usize, test_if_false: usize) -> Self {
+    pub fn new(start_items: Vec<usize>, operation: Expr, test_div: usize, test_if_true: usize, test_if_false: usize) -> Self {
         Self { start_items, operation, test_div, test_if_true, test_if_false }
     }
 
@@ -245,14 +132,37 @@ impl Monkey {
     pub fn test_if_false(&self) -> usize {
         self.test_if_false
     }
-    pub fn operation(&self) -> &Operation {
+    pub fn operation(&self) -> &Expr {
         &self.operation
     }
 }
 
-pub enum Operation {
-    MultiplyOld,
-    AddOld,
-    MultiplyValue(usize),
-    AddValue(usize),
-}
\ No newline at end of file
+#[cfg(test)]
+pub fn example_game<W>() -> Game<W> {
+    use crate::lib::common::InputSource;
+
+    let scope = PuzzleScope::create(2022, 11, 1)
+        .with_source(InputSource::File("puzzles/2022/day11/puzzle0.txt".into()));
+    Game::new(parse_input(&scope, "puzzle0").unwrap())
+}
+
+// a subtraction must not fail on levels reduced by the modulus
+#[test]
+fn play_game_subtracts_modulo() {
+    use num_bigint::BigUint;
+
+    let monkeys = || vec!(
+        Monkey::new(vec!(20, 9), "old * 3 - 7".parse().unwrap(), 2, 1, 0),
+        Monkey::new(vec!(14), "old + 4".parse().unwrap(), 3, 0, 1),
+    );
+    let mut small: Game = Game::new(monkeys());
+    play_game(&mut small, 10, 1, 6, &mut []).unwrap();
+    let mut big: Game<BigUint> = Game::new(monkeys());
+    play_game(&mut big, 10, 1, 1, &mut []).unwrap();
+
+    assert_eq!(small.inspections, big.inspections);
+    for (monkey_id, items) in &big.items {
+        let reduced: Vec<usize> = items.iter().map(|level| level.rem_by(6)).collect();
+        assert_eq!(small.items[monkey_id], reduced);
+    }
+}
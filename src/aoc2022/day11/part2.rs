@@ -1,36 +1,73 @@
-use std::collections::HashMap;
+use anyhow::{bail, Result};
+use num_bigint::BigUint;
 
+use crate::aoc2022::day11::events::Logger;
 use crate::aoc2022::day11::part1::{Game, parse_input, play_game};
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use crate::lib::common::{Answer, PuzzleScope};
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
-
-fn execute(scope: &PuzzleScope) {
-    let mut game = Game {
-        monkeys: parse_input(scope, "puzzle1"),
-        items: HashMap::new(),
-        inspections: HashMap::new(),
-    };
-
-    let rounds = 10_000;
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let monkeys = parse_input(scope, scope.puzzle())?;
 
     // product of all divisors
-    let divisor: usize = game.monkeys.iter()
+    let divisor: usize = monkeys.iter()
         .map(|m| m.test_div())
         .fold(1, |a, b| a * b);
     println!("Use divisors {}", divisor);
 
-    play_game(&mut game, rounds, false, rounds + 1, 1000, 1, divisor);
+    // `worry big` plays with the real levels instead, a few rounds only as they grow quickly
+    let worry: String = scope.param("worry").unwrap_or_else(|_| "small".to_string());
+    let result = match worry.as_str() {
+        "small" => {
+            let rounds = scope.param("rounds").unwrap_or(10_000);
+            let mut game: Game = Game::new(monkeys);
+            let mut logger = Logger { steps: false, summary_every: 0, inspections_every: 1000 };
+            play_game(&mut game, rounds, 1, divisor, &mut [&mut logger])?;
+            game.monkey_business()
+        }
+        "big" => {
+            let rounds = scope.param("rounds").unwrap_or(20);
+            let mut game: Game<BigUint> = Game::new(monkeys);
+            let mut logger = Logger { steps: false, summary_every: 0, inspections_every: rounds };
+            play_game(&mut game, rounds, 1, 1, &mut [&mut logger])?;
+
+            let mut small: Game = Game::new(parse_input(scope, scope.puzzle())?);
+            play_game(&mut small, rounds, 1, divisor, &mut [])?;
+            if small.inspections != game.inspections {
+                bail!("inspections differ with the modulus after {} rounds", rounds);
+            }
+            let bits = game.items.values().flatten().map(|level| level.bits()).max().unwrap_or(0);
+            println!("Same inspections with the modulus, the levels have up to {} bits", bits);
+            game.monkey_business()
+        }
+        _ => bail!("invalid worry '{}', expected 'small' or 'big'", worry),
+    };
     println!();
 
-    let mut mm: Vec<(usize, usize)> = vec!();
-    game.inspections.iter().for_each(|(monkey_id, count)| mm.push((*count, *monkey_id)));
-    mm.sort_by(|a, b| b.0.cmp(&a.0));
-    let result = mm.get(0).unwrap().0 * mm.get(1).unwrap().0;
+    Ok(Answer::from(result))
+}
+
+// the modulus keeps the divisibility tests and so the throws the same as with the real levels
+#[test]
+fn modulus_matches_big_integers() {
+    use crate::aoc2022::day11::expr::Worry;
+    use crate::aoc2022::day11::part1::example_game;
+
+    let rounds = 20;
+
+    let mut small: Game = example_game();
+    let divisor: usize = small.monkeys.iter().map(|m| m.test_div()).product();
+    play_game(&mut small, rounds, 1, divisor, &mut []).unwrap();
+
+    let mut big: Game<BigUint> = example_game();
+    play_game(&mut big, rounds, 1, 1, &mut []).unwrap();
 
-    write_solution(&scope, format!("result = {}", result).as_str());
+    assert_eq!(small.inspections, big.inspections);
+    for (monkey_id, items) in &big.items {
+        let reduced: Vec<usize> = items.iter().map(|level| level.rem_by(divisor)).collect();
+        assert_eq!(small.items[monkey_id], reduced);
+    }
+    // too big for a u64 already, the modulus keeps them below 96577
+    assert!(big.items.values().flatten().any(|level| level.bits() > 64));
+    // "== After round 20 ==" of the example
+    assert_eq!((0..4).map(|id| big.inspections[&id]).collect::<Vec<_>>(), vec!(99, 97, 8, 103));
 }
@@ -1,25 +1,16 @@
-use std::cell::RefCell;
-use crate::aoc2022::day12::part1::{fewest_steps, Input, parse_input};
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use anyhow::{anyhow, Result};
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+use crate::aoc2022::day12::part1::{fewest_steps, parse_input};
+use crate::lib::common::{Answer, PuzzleScope};
+use crate::lib::point::Point2D;
 
-fn execute(scope: &PuzzleScope) {
-    let input = parse_input(scope, "puzzle1");
-    let starts: Vec<(i32, i32)> = input.map.iter()
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let input = parse_input(scope, scope.puzzle())?;
+    // searching from all of them at once finds the closest one
+    let starts: Vec<Point2D> = input.map.cells()
         .filter(|(_, c)| **c == 'a' || **c == 'S')
-        .map(|(pos, _)| pos.clone())
+        .map(|(pos, _)| pos)
         .collect();
-    let min_cost: RefCell<i32> = RefCell::new(i32::MAX);
-    for start in starts {
-        let (_, cost) = fewest_steps(Input {start, map: input.map.clone()});
-        if let Some(v) = cost {
-            min_cost.replace(min_cost.clone().into_inner().min(v));
-        }
-    }
-    write_solution(&scope, format!("cost = {}", min_cost.into_inner()).as_str());
-}
\ No newline at end of file
+    let (_, cost) = fewest_steps(&input, starts);
+    Ok(Answer::from(cost.ok_or_else(|| anyhow!("no path to the best signal"))?))
+}
@@ -1,16 +1,12 @@
 use std::cmp::Ordering;
 
-use crate::aoc2022::lib::common::{measure_time_and_print, parse_int, PuzzleScope};
-use crate::aoc2022::lib::io;
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use anyhow::Result;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+use crate::lib::common::{Answer, parse_int, PuzzleScope};
+use crate::lib::io;
 
-fn execute(scope: &PuzzleScope) {
-    let packets = parse_input(scope, "puzzle1");
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let packets = parse_input(scope, scope.puzzle())?;
 
     println!("Packets");
     packets.iter().for_each(|p| {
@@ -31,7 +27,7 @@ fn execute(scope: &PuzzleScope) {
         }
     }
 
-    write_solution(&scope, format!("sum = {}", sum).as_str());
+    Ok(Answer::from(sum))
 }
 
 pub enum Packet {
@@ -133,15 +129,15 @@ impl Clone for Packet {
     }
 }
 
-pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Vec<Packet> {
-    io::read_puzzle_as_list(scope.day(), puzzle)
+pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Result<Vec<Packet>> {
+    Ok(io::read_puzzle_as_list(scope, puzzle)?
         .iter()
         .filter(|s| !s.is_empty())
         .map(|str| {
             let (_, packet) = parse_packet(str, 0);
             packet
         })
-        .collect()
+        .collect())
 }
 
 fn parse_packet(str: &str, offset: usize) -> (usize, Packet) {
@@ -1,15 +1,11 @@
 use std::borrow::Borrow;
-use crate::aoc2022::day13::part1::{Packet, parse_input};
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use anyhow::Result;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+use crate::aoc2022::day13::part1::{Packet, parse_input};
+use crate::lib::common::{Answer, PuzzleScope};
 
-fn execute(scope: &PuzzleScope) {
-    let mut packets = parse_input(scope, "puzzle1");
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let mut packets = parse_input(scope, scope.puzzle())?;
     let divider1 = Packet::LIST(vec!(Packet::LIST(vec!(Packet::VALUE(2)))));
     let divider2 = Packet::LIST(vec!(Packet::LIST(vec!(Packet::VALUE(6)))));
     packets.push(divider1.clone());
@@ -34,5 +30,5 @@ fn execute(scope: &PuzzleScope) {
         }
     }
 
-    write_solution(&scope, format!("signal = {}", divider1_idx * divider2_idx).as_str());
+    Ok(Answer::from(divider1_idx * divider2_idx))
 }
\ No newline at end of file
@@ -1,29 +1,24 @@
-use std::collections::HashMap;
+use anyhow::Result;
 
-use crate::aoc2022::lib::common::{measure_time_and_print, parse_int, PuzzleScope};
-use crate::aoc2022::lib::io;
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use crate::lib::common::{Answer, parse_int, PuzzleScope};
+use crate::lib::grid::SparseGrid;
+use crate::lib::image::{BLACK, BLUE, FrameRecorder, GRAY, Image, RED, Rgb, SAND};
+use crate::lib::io;
+use crate::lib::point::Point2D;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
-
-fn execute(scope: &PuzzleScope) {
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
     let source = (500, 0);
-    let mut carve = build_carve(parse_input(scope, "puzzle1"));
-    carve.insert(source.clone(), Pixel::Source);
+    let mut carve = build_carve(parse_input(scope, scope.puzzle())?);
+    carve.insert(Point2D::create(source.0, source.1), Pixel::Source);
 
     println!("START");
     print_carve(&carve);
     println!();
 
-    let max_y: i32 = carve.iter()
-        .map(|((_, y), _)| y.clone())
-        .max()
-        .unwrap();
+    let max_y: i32 = carve.bounds().unwrap().1.y();
 
-    let_that_sink_in(&mut carve, &source, max_y);
+    let mut recorder = FrameRecorder::for_scope(scope, "sand")?.scale(4);
+    let_that_sink_in(&mut carve, &source, max_y, &mut recorder)?;
 
     println!("END");
     print_carve(&carve);
@@ -38,10 +33,10 @@ fn execute(scope: &PuzzleScope) {
         })
         .count();
 
-    write_solution(&scope, format!("count = {}", sand_count).as_str());
+    Ok(Answer::from(sand_count))
 }
 
-pub fn let_that_sink_in(carve: &mut HashMap<(i32, i32), Pixel>, source: &(i32, i32), max_y: i32) {
+pub fn let_that_sink_in(carve: &mut SparseGrid<Pixel>, source: &(i32, i32), max_y: i32, recorder: &mut FrameRecorder) -> Result<()> {
     let mut tick = 0;
     loop {
         //println!();
@@ -61,24 +56,18 @@ pub fn let_that_sink_in(carve: &mut HashMap<(i32, i32), Pixel>, source: &(i32, i
             let mut found = false;
             let candidates = vec!((current.0, current.1 + 1), (current.0 - 1, current.1 + 1), (current.0 + 1, current.1 + 1));
             for nn in candidates.clone() {
-                match carve.get(&nn) {
-                    Some(Pixel::Flow) => {
-                        desired_state = Some(Pixel::Flow);
-                        found = true;
-                        break;
-                    }
-                    _ => ()
+                if let Some(Pixel::Flow) = carve.get(&Point2D::create(nn.0, nn.1)) {
+                    desired_state = Some(Pixel::Flow);
+                    found = true;
+                    break;
                 }
             }
             if !found {
                 for nn in candidates {
-                    match carve.get(&nn) {
-                        None => {
-                            current = nn.clone();
-                            found = true;
-                            break;
-                        }
-                        _ => ()
+                    if carve.get(&Point2D::create(nn.0, nn.1)).is_none() {
+                        current = nn;
+                        found = true;
+                        break;
                     }
                 }
             }
@@ -90,19 +79,21 @@ pub fn let_that_sink_in(carve: &mut HashMap<(i32, i32), Pixel>, source: &(i32, i
         }
 
         if let Some(pixel) = desired_state {
-            carve.insert(current.clone(), pixel);
+            carve.insert(Point2D::create(current.0, current.1), pixel);
         } else {
-            carve.insert(current.clone(), Pixel::Rested);
+            carve.insert(Point2D::create(current.0, current.1), Pixel::Rested);
         }
+        recorder.record(|| carve_to_image(carve))?;
 
         if current == source.clone() {
             break;
         }
     }
+    recorder.finish(|| carve_to_image(carve))
 }
 
-pub fn build_carve(input: Vec<Vec<(i32, i32)>>) -> HashMap<(i32, i32), Pixel> {
-    let mut map: HashMap<(i32, i32), Pixel> = HashMap::new();
+pub fn build_carve(input: Vec<Vec<(i32, i32)>>) -> SparseGrid<Pixel> {
+    let mut map: SparseGrid<Pixel> = SparseGrid::new();
     for path in input {
         for i in 1..path.len() {
             let from = path.get(i - 1).unwrap().clone();
@@ -112,14 +103,14 @@ pub fn build_carve(input: Vec<Vec<(i32, i32)>>) -> HashMap<(i32, i32), Pixel> {
                 let y_min = from.1.min(to.1);
                 let y_max = from.1.max(to.1);
                 for y in y_min..=y_max {
-                    map.insert((x, y), Pixel::Rock);
+                    map.insert(Point2D::create(x, y), Pixel::Rock);
                 }
             } else if from.1 == to.1 {
                 let y = from.1;
                 let x_min = from.0.min(to.0);
                 let x_max = from.0.max(to.0);
                 for x in x_min..=x_max {
-                    map.insert((x, y), Pixel::Rock);
+                    map.insert(Point2D::create(x, y), Pixel::Rock);
                 }
             }
         }
@@ -134,28 +125,26 @@ pub enum Pixel {
     Flow,
 }
 
-pub fn print_carve(carve: &HashMap<(i32, i32), Pixel>) {
-    let x_max: i32 = carve.keys()
-        .into_iter()
-        .map(|(x, _)| x)
-        .max()
-        .unwrap()
-        .clone();
-    let y_max: i32 = carve.keys()
-        .into_iter()
-        .map(|(_, y)| y)
-        .max()
-        .unwrap()
-        .clone();
-    for y in 0..=y_max {
-        let mut line = "".to_owned();
-        for x in 0..=x_max {
-            match carve.get(&(x, y)) {
-                Some(pixel) => line = line + pixel.to_string().as_str(),
-                None => line = line + " ",
-            }
+pub fn print_carve(carve: &SparseGrid<Pixel>) {
+    let (_, max) = carve.bounds().unwrap();
+    print!("{}", carve.render_region(Point2D::create(0, 0), max, |pixel| match pixel {
+        Some(pixel) => pixel.to_string().chars().next().unwrap(),
+        None => ' ',
+    }));
+}
+
+pub fn carve_to_image(carve: &SparseGrid<Pixel>) -> Image {
+    Image::from_sparse(carve, |pixel| pixel.map(Pixel::color).unwrap_or(BLACK))
+}
+
+impl Pixel {
+    fn color(&self) -> Rgb {
+        match self {
+            Pixel::Rock => GRAY,
+            Pixel::Source => RED,
+            Pixel::Rested => SAND,
+            Pixel::Flow => BLUE,
         }
-        println!("{}", line);
     }
 }
 
@@ -170,8 +159,8 @@ impl ToString for Pixel {
     }
 }
 
-pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Vec<Vec<(i32, i32)>> {
-    parse_lines(io::read_puzzle_as_list(scope.day(), puzzle))
+pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Result<Vec<Vec<(i32, i32)>>> {
+    Ok(parse_lines(io::read_puzzle_as_list(scope, puzzle)?))
 }
 
 pub fn parse_lines(lines: Vec<String>) -> Vec<Vec<(i32, i32)>> {
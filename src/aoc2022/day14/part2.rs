@@ -1,27 +1,20 @@
-use crate::aoc2022::day14::part1::{build_carve, let_that_sink_in, parse_input, Pixel, print_carve};
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use anyhow::Result;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+use crate::aoc2022::day14::part1::{build_carve, let_that_sink_in, parse_input, Pixel, print_carve};
+use crate::lib::common::{Answer, PuzzleScope};
+use crate::lib::image::FrameRecorder;
+use crate::lib::point::Point2D;
 
-fn execute(scope: &PuzzleScope) {
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
     let source = (500, 0);
-    let mut carve = build_carve(parse_input(scope, "puzzle1"));
-    carve.insert(source.clone(), Pixel::Source);
-
-    let max_y: i32 = carve.iter()
-        .map(|((_, y), _)| y.clone())
-        .max()
-        .unwrap() + 2;
-    let max_x: i32 = carve.iter()
-        .map(|((x, _), _)| x.clone())
-        .max()
-        .unwrap();
-    for x in -max_x..2 * max_x {
-        carve.insert((x, max_y), Pixel::Rock);
+    let mut carve = build_carve(parse_input(scope, scope.puzzle())?);
+    carve.insert(Point2D::create(source.0, source.1), Pixel::Source);
+
+    let (_, max) = carve.bounds().unwrap();
+    let max_y: i32 = max.y() + 2;
+    // the floor only needs to be as wide as the sand pile can get
+    for x in source.0 - max_y..=source.0 + max_y {
+        carve.insert(Point2D::create(x, max_y), Pixel::Rock);
     }
 
     println!("START");
@@ -29,7 +22,8 @@ fn execute(scope: &PuzzleScope) {
     println!();
 
     // 2117
-    let_that_sink_in(&mut carve, &source, max_y);
+    let mut recorder = FrameRecorder::for_scope(scope, "sand")?.scale(2);
+    let_that_sink_in(&mut carve, &source, max_y, &mut recorder)?;
 
     println!("END");
     print_carve(&carve);
@@ -44,5 +38,5 @@ fn execute(scope: &PuzzleScope) {
         })
         .count();
 
-    write_solution(&scope, format!("count = {}", sand_count).as_str());
+    Ok(Answer::from(sand_count))
 }
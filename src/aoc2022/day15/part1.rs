@@ -2,20 +2,19 @@ use std::borrow::Borrow;
 use std::collections::HashMap;
 use std::ops::RangeInclusive;
 
-use crate::aoc2022::lib::common::{measure_time_and_print, parse_int, PuzzleScope};
-use crate::aoc2022::lib::io;
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use anyhow::Result;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+use crate::lib::common::{Answer, parse_int, PuzzleScope};
+use crate::lib::grid::SparseGrid;
+use crate::lib::interval::IntervalSet;
+use crate::lib::io;
+use crate::lib::point::Point2D;
 
-fn execute(scope: &PuzzleScope) {
-    let input = parse_input(scope, "puzzle0");
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let input = parse_input(scope, scope.puzzle())?;
     let map = build_map(input.borrow());
-    let row_in_question = 2_000_000;
-    write_solution(&scope, format!("count covered in row {} = {}", row_in_question, map.count_covered_in_row(row_in_question)).as_str());
+    let row_in_question: i32 = scope.param("row")?;
+    Ok(Answer::from(map.count_covered_in_row(row_in_question)))
 }
 
 pub struct Sensor {
@@ -42,172 +41,43 @@ impl Sensor {
 }
 
 pub struct Map {
-    pub data: HashMap<(i32, i32), Marker>,
+    pub data: SparseGrid<Marker>,
     pub signal_distances: HashMap<(i32, i32), i32>,
-    pub min: (i32, i32),
-    pub max: (i32, i32),
-}
-
-struct MultiRange {
-    ranges: Vec<(i32, i32)>,
-}
-
-impl MultiRange {
-    fn new() -> Self {
-        Self { ranges: vec!() }
-    }
-
-    fn append(&mut self, range: RangeInclusive<i32>) {
-        // see also
-        // https://github.com/Killavus/Advent-of-Code-2022/blob/aec9418dd4e219863c2a1552d8275806aafc124b/15-beacon-exclusion-zone/src/main.rs
-
-        let mut idx = 0;
-        let mut overlaps = vec!();
-
-        while idx < self.ranges.len() {
-            let (start, end) = self.ranges[idx];
-            if range.contains(&start)
-                || range.contains(&end)
-                || (start..=end).contains(range.start())
-                || (start..=end).contains(range.end())
-            {
-                overlaps.push(self.ranges.remove(idx));
-            } else {
-                idx = idx + 1;
-            }
-        }
-
-        // combine
-        let result_range = overlaps
-            .into_iter()
-            .fold(range, |acc, (start, end)| {
-                *acc.start().min(&start)..=*acc.end().max(&end)
-            });
-
-        self.ranges.push((*result_range.start(), *result_range.end()));
-    }
-
-    fn reduce_to(&mut self, limit_range: RangeInclusive<i32>) {
-        let mut idx = 0;
-        while idx < self.ranges.len() {
-            let (start, end) = self.ranges[idx];
-            if limit_range.contains(&start)
-                || limit_range.contains(&end)
-                || (start..=end).contains(limit_range.start())
-                || (start..=end).contains(limit_range.end())
-            {
-                self.ranges[idx] = (start.max(*limit_range.start()), end.min(*limit_range.end()));
-                idx = idx + 1;
-            } else {
-                self.ranges.remove(idx);
-            }
-        }
-
-        self.ranges.sort_by_key(|(start, _)| *start);
-    }
-
-    fn coverage(&self) -> i32 {
-        self.ranges
-            .iter()
-            .map(|(start, end)| (end - start + 1).abs())
-            .sum()
-    }
-
-    fn first_gap(&self) -> Option<i32> {
-        for ranges in self.ranges.as_slice().windows(2) {
-            let (_, end) = ranges[0];
-            let (start, _) = ranges[1];
-            if start - end == 2 { // why not more than 2?
-                return Some(end + 1);
-            }
-        }
-        None
-    }
 }
 
-
 impl Map {
-    pub fn build_min_max(map: &HashMap<(i32, i32), Marker>) -> ((i32, i32), (i32, i32)) {
-        let all_pos: Vec<(i32, i32)> = map
-            .iter()
-            .map(|(pos, _)| pos.clone())
-            .collect();
-
-        let min_x: i32 = all_pos.iter()
-            .map(|(x, _)| x.clone())
-            .min()
-            .unwrap();
-        let max_x: i32 = all_pos.iter()
-            .map(|(x, _)| x.clone())
-            .max()
-            .unwrap();
-        let min_y: i32 = all_pos.iter()
-            .map(|(_, y)| y.clone())
-            .min()
-            .unwrap();
-        let max_y: i32 = all_pos.iter()
-            .map(|(_, y)| y.clone())
-            .max()
-            .unwrap();
-
-        ((min_x, min_y), (max_x, max_y))
-    }
-
     pub fn count_covered_in_row(&self, y: i32) -> i32 {
-        let signal_min_x: i32 = self.signal_distances
-            .iter()
-            .map(|(pos, distance)| pos.0 - distance)
-            .min()
-            .unwrap();
-        let signal_max_x: i32 = self.signal_distances
-            .iter()
-            .map(|(pos, distance)| pos.0 + distance)
-            .max()
-            .unwrap();
-        let range_min = self.min.0.clone() + signal_min_x;
-        let range_max = self.max.0.clone() + signal_max_x;
-        (range_min..=range_max).into_iter()
-            .filter(|&x| {
-                let pos = (x, y);
-                // filter out sensor and beacons
-                if self.data.get(pos.borrow()).is_some() {
-                    return false;
-                }
-                self.signal_distances
-                    .iter()
-                    .any(|(&signal_pos, &signal_distance)| {
-                        Sensor::manhatten_distance(pos.clone(), signal_pos.clone()) <= signal_distance
-                    })
-            })
-            .count() as i32
+        let covered = self.covered_in_row(y);
+        // sensors and beacons are never a free position
+        let occupied = self.data.points()
+            .filter(|p| p.y() == y && covered.contains(&p.x()))
+            .count();
+        covered.len() as i32 - occupied as i32
     }
 
     pub fn find_uncovered(&self, limits: RangeInclusive<i32>) -> Option<(i32, i32)> {
-        for y in limits.clone() {
-            //println!("y={}", y);
-            let mut multi_range = MultiRange::new();
-            self.signal_distances
-                .iter()
-                .filter(|(signal, &distance)| (y - signal.1).abs() <= distance)
-                .for_each(|(signal, distance)| {
-                    // this cost me much.. the scale is reversed!
-                    let reverse_scale = distance - (y - signal.1).abs();
-                    multi_range.append((signal.0 - reverse_scale)..=(signal.0 + reverse_scale));
-                });
-            multi_range.reduce_to(limits.clone());
-            let cov = multi_range.coverage();
-            //println!("  cov={}", cov);
-            if cov == limits.clone().last().unwrap() {
-                return multi_range.first_gap().map(|x| (x, y));
-            }
-        }
+        limits.clone()
+            .find_map(|y| {
+                let uncovered = self.covered_in_row(y).complement(limits.clone());
+                uncovered.span().map(|r| (*r.start(), y))
+            })
+    }
 
-        None
+    fn covered_in_row(&self, y: i32) -> IntervalSet<i32> {
+        self.signal_distances
+            .iter()
+            .filter(|(signal, &distance)| (y - signal.1).abs() <= distance)
+            .map(|(signal, distance)| {
+                // this cost me much.. the scale is reversed!
+                let reverse_scale = distance - (y - signal.1).abs();
+                (signal.0 - reverse_scale)..=(signal.0 + reverse_scale)
+            })
+            .collect()
     }
 }
 
-pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Vec<Sensor> {
-    io::read_puzzle_as_list(scope.day(), puzzle)
+pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Result<Vec<Sensor>> {
+    Ok(io::read_puzzle_as_list(scope, puzzle)?
         .iter()
         .map(|line| {
             let split: Vec<&str> = line.split(": ").collect();
@@ -229,7 +99,7 @@ pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Vec<Sensor> {
             let beacon_y: i32 = parse_int(beacon_pos.get(1).unwrap().strip_prefix("y=").unwrap());
             Sensor::new(sensor_x, sensor_y, beacon_x, beacon_y)
         })
-        .collect()
+        .collect())
 }
 
 #[derive(PartialEq, Eq)]
@@ -248,44 +118,28 @@ impl ToString for Marker {
 }
 
 pub fn build_map(input: &[Sensor]) -> Map {
-    let mut map = HashMap::new();
+    let mut map = SparseGrid::new();
     let mut signal_distances = HashMap::new();
     for sensor in input {
-        signal_distances.insert(sensor.pos.clone(), sensor.distance);
-        map.entry(sensor.pos.clone())
-            .and_modify(|_| unreachable!())
-            .or_insert(Marker::Sensor);
-        map.entry(sensor.closest_beacon.clone())
-            // beacon marked more than once, otherwise error
-            .and_modify(|x| match x {
-                Marker::Beacon => (),
-                _ => unreachable!()
-            })
-            .or_insert(Marker::Beacon);
+        signal_distances.insert(sensor.pos, sensor.distance);
+        let (x, y) = sensor.pos;
+        if map.insert(Point2D::create(x, y), Marker::Sensor).is_some() {
+            unreachable!()
+        }
+        // beacon marked more than once, otherwise error
+        let (x, y) = sensor.closest_beacon;
+        if let Some(Marker::Sensor) = map.insert(Point2D::create(x, y), Marker::Beacon) {
+            unreachable!()
+        }
     }
 
-    let (min, max) = Map::build_min_max(map.borrow());
-
-    Map { data: map, min, max, signal_distances }
+    Map { data: map, signal_distances }
 }
 
 pub fn _map_to_string(map: &Map) -> String {
-    let mut result = "".to_owned();
-
-    for y in map.min.1..=map.max.1 {
-        result = result + format!("{:010}  ", y).as_str();
-        for x in map.min.0..=map.max.0 {
-            match map.data.get(&(x, y)) {
-                Some(marker) => {
-                    result = result + marker.to_string().as_str()
-                }
-                None => result = result + " ",
-            }
-        }
-        result = result + "\n";
-    }
-
-    result
+    map.data.render(|marker| match marker {
+        Some(Marker::Beacon) => 'B',
+        Some(Marker::Sensor) => 'S',
+        None => ' ',
+    })
 }
-
-
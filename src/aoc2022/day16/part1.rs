@@ -1,18 +1,16 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashMap, HashSet};
 
-use crate::aoc2022::lib::common::{measure_time_and_print, parse_int, PuzzleScope};
-use crate::aoc2022::lib::io;
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use anyhow::Result;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+use crate::lib::common::{Answer, parse_int, PuzzleScope};
+use crate::lib::io;
+use crate::lib::search;
+use crate::lib::search::SearchSpace;
 
-fn execute(scope: &PuzzleScope) {
-    let input = parse_input(scope, "puzzle0");
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let input = parse_input(scope, scope.puzzle())?;
     let (max_pressure, _) = resolve_max_pressure(&input, 30);
-    write_solution(&scope, format!("max = {:?}", max_pressure).as_str());
+    Ok(Answer::from(max_pressure))
 }
 
 pub fn resolve_max_pressure(input: &Vec<Valve>, limit: i32) -> (i32, Vec<String>) {
@@ -59,33 +57,35 @@ pub fn resolve_max_pressure0(current: &str, minutes_left: i32, nodes: &mut HashS
 }
 
 pub fn build_node_dists(input: &Vec<Valve>, map: &HashMap<String, &Valve>) -> HashMap<(String, String), i32> {
+    let tunnels = Tunnels { map };
     let mut dists: HashMap<(String, String), i32> = HashMap::new();
-    let mut seen = HashSet::new();
     input.iter().for_each(|from| {
-        let mut q = VecDeque::new();
-        q.push_back(from.id.clone());
-        let mut d = 0;
-        dists.insert((from.id.clone(), from.id.clone()), 0);
-        while !q.is_empty() {
-            d += 1;
-            let mut next = vec!();
-            while let Some(pos_via) = q.pop_front() {
-                for pos_to in map.get(&pos_via).unwrap().options.clone() {
-                    if !seen.contains(&(from.id.clone(), pos_to.clone())) {
-                        next.push(pos_to.clone());
-                        dists.insert((from.id.clone(), pos_to.clone()), d);
-                        seen.insert((from.id.clone(), pos_to.clone()));
-                    }
-                }
-            }
-            while let Some(n) = next.pop() {
-                q.push_back(n);
-            }
+        for (to, d) in search::distances(&tunnels, from.id.clone()) {
+            dists.insert((from.id.clone(), to), d as i32);
         }
     });
     dists
 }
 
+// each tunnel takes one minute
+struct Tunnels<'a> {
+    map: &'a HashMap<String, &'a Valve>,
+}
+
+impl SearchSpace for Tunnels<'_> {
+    type State = String;
+
+    fn neighbours(&self, id: &String) -> Vec<(String, usize)> {
+        self.map.get(id).unwrap().options.iter()
+            .map(|to| (to.clone(), 1))
+            .collect()
+    }
+
+    fn is_goal(&self, _: &String) -> bool {
+        false
+    }
+}
+
 pub fn build_node_map(input: &Vec<Valve>) -> HashMap<String, &Valve> {
     let mut map = HashMap::new();
     input.iter().for_each(|v| {
@@ -106,8 +106,8 @@ impl Valve {
     }
 }
 
-pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Vec<Valve> {
-    io::read_puzzle_as_list(scope.day(), puzzle)
+pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Result<Vec<Valve>> {
+    Ok(io::read_puzzle_as_list(scope, puzzle)?
         .iter()
         .map(|line| {
             let parts: Vec<&str> = line.split("; tunnel leads to valve ")
@@ -127,5 +127,5 @@ pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Vec<Valve> {
                 options,
             )
         })
-        .collect()
+        .collect())
 }
\ No newline at end of file
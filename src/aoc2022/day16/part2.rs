@@ -1,20 +1,15 @@
 use std::collections::HashSet;
 
+use anyhow::Result;
 use itertools::Itertools;
 
 use crate::aoc2022::day16::part1::{build_node_dists, build_node_map, parse_input, resolve_max_pressure0, Valve};
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use crate::lib::common::{Answer, PuzzleScope};
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
-
-fn execute(scope: &PuzzleScope) {
-    let input = parse_input(scope, "puzzle1");
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let input = parse_input(scope, scope.puzzle())?;
     let max_pressure = resolve_with_an_elephant_in_the_room(&input);
-    write_solution(&scope, format!("max = {:?}", max_pressure).as_str());
+    Ok(Answer::from(max_pressure))
 }
 
 fn resolve_with_an_elephant_in_the_room(input: &Vec<Valve>) -> i32 {
@@ -1,160 +1,74 @@
 use std::collections::HashSet;
 
+use anyhow::Result;
 use itertools::Itertools;
 
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::io;
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use crate::lib::common::{Answer, PuzzleScope};
+use crate::lib::cycle;
+use crate::lib::io;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
-
-fn execute(scope: &PuzzleScope) {
-    let input = parse_input(scope, "puzzle1");
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let input = parse_input(scope, scope.puzzle())?;
     let mut direction_generator = build_direction_generator(input);
     let mut chamber = Chamber::new(7, 0);
     let mut shape_generator = build_shape_generator();
-    let size = tower_height(2022, &mut chamber, &mut shape_generator, &mut direction_generator);
-    write_solution(&scope, format!("height = {}", size).as_str());
+    let size = tower_height(scope.param("rocks1")?, &mut chamber, &mut shape_generator, &mut direction_generator);
+    Ok(Answer::from(size))
 }
 
 pub fn tower_height(limit: usize, chamber: &mut Chamber, shape_generator: &mut MyGenerator<Shape>, direction_generator: &mut MyGenerator<Direction>) -> usize {
-    //println!("{}", chamber.to_string());
-
-    let mut lengths: Vec<usize> = vec![];
-    let mut last_length = 0;
+    // after a while the surface, the next rock and the next jet repeat, and so does the growth
+    let height = cycle::extrapolate(
+        (chamber, shape_generator, direction_generator),
+        limit,
+        |(chamber, shapes, directions)| drop_rock(chamber, shapes, directions),
+        |(chamber, shapes, directions)| (shapes.position(), directions.position(), chamber.skyline()),
+        |(chamber, _, _)| chamber.height as i64,
+    );
+    height as usize
+}
 
-    for i in 0..limit {
-        let shape = shape_generator.next();
-        let mut position = (3, chamber.height + shape.height + 3);
+fn drop_rock(chamber: &mut Chamber, shape_generator: &mut MyGenerator<Shape>, direction_generator: &mut MyGenerator<Direction>) {
+    let shape = shape_generator.next();
+    let mut position = (3, chamber.height + shape.height + 3);
 
-        //println!();
-        //println!("{}", "=".repeat(100));
-        //println!();
+    //println!();
+    //println!("{}", "=".repeat(100));
+    //println!();
 
-        loop {
-            //chamber._with_shape_at(shape.clone(), position);
-            //println!("{}", chamber.to_string());
-            let dir_type = direction_generator.next();
+    loop {
+        //chamber._with_shape_at(shape.clone(), position);
+        //println!("{}", chamber.to_string());
+        let dir_type = direction_generator.next();
 
-            match dir_type {
-                Direction::Left => {
-                    if !chamber.conflicts(&shape, &(position.0 - 1, position.1)) {
-                        position = (position.0 - 1, position.1);
-                        //println!("left {:?}", position);
-                    }
-                }
-                Direction::Right => {
-                    if !chamber.conflicts(&shape, &(position.0 + 1, position.1)) {
-                        position = (position.0 + 1, position.1);
-                        //println!("right {:?}", position);
-                    }
+        match dir_type {
+            Direction::Left => {
+                if !chamber.conflicts(&shape, &(position.0 - 1, position.1)) {
+                    position = (position.0 - 1, position.1);
+                    //println!("left {:?}", position);
                 }
             }
-
-            //chamber._with_shape_at(shape.clone(), position);
-            //println!("{}", chamber.to_string());
-
-            if chamber.conflicts(&shape, &(position.0, position.1 - 1)) {
-                //println!("fill");
-                chamber.fill_at(shape, position);
-                break;
-            } else {
-                position = (position.0, position.1 - 1);
+            Direction::Right => {
+                if !chamber.conflicts(&shape, &(position.0 + 1, position.1)) {
+                    position = (position.0 + 1, position.1);
+                    //println!("right {:?}", position);
+                }
             }
-
-            //println!("down {:?}", position);
         }
 
-        //println!();
-        //println!("{}", "=".repeat(100));
-        //println!();
-
-        //chamber._remove_shape();
+        //chamber._with_shape_at(shape.clone(), position);
         //println!("{}", chamber.to_string());
-        //println!();
-
-        lengths.push(chamber.height - last_length);
-        last_length = chamber.height;
-
-        if i > 5000 {
-            // ok, that dynamic programming stuff is borred here: https://gist.githubusercontent.com/p-a/bef2f3f58b6907196083adf28fbee636/raw/1909a87cfe119b6f04d65cb63c7d00c01d81a27f/aoc_2022_day17.js
-            let (sequence, seq_index) = find_sequence(lengths.as_slice());
-            let pattern = find_pattern(sequence);
-            let pattern_height: usize = pattern.iter().sum();
-            let repetitions = (limit - seq_index) / pattern.len();
-            let steps_per_sequence = (limit - seq_index) % pattern.len();
-            let x: usize = pattern[0..steps_per_sequence].iter().sum::<usize>() + lengths.as_slice()[0..seq_index].iter().sum::<usize>();
 
-            let res = x + repetitions * pattern_height;
-            return res;
+        if chamber.conflicts(&shape, &(position.0, position.1 - 1)) {
+            //println!("fill");
+            chamber.fill_at(shape, position);
+            break;
+        } else {
+            position = (position.0, position.1 - 1);
         }
-    }
 
-    //println!();
-    //println!("FINISH");
-    //println!("{}", "=".repeat(100));
-    //println!();
-
-    //chamber._remove_shape();
-    //println!("{}", chamber.to_string());
-    //println!();
-
-    chamber.height
-}
-
-fn find_sequence(arr: &[usize]) -> (&[usize], usize) {
-    let len = arr.len();
-    let mut dp: Vec<Vec<isize>> = (0..=len)
-        .map(|_| (0..=len)
-            .map(|_| 0)
-            .collect_vec()
-        )
-        .collect_vec();
-    let mut seq_len = 0;
-    let mut index = 0;
-    for i in 0..len {
-        let a = arr[i];
-        for j in i + 2..=len {
-            if a == arr[j - 1] && dp[i][j - 1] < (j - i) as isize {
-                dp[i + 1][j] = dp[i][j - 1] + 1;
-                if dp[i + 1][j] > seq_len {
-                    seq_len = dp[i + 1][j];
-                    index = index.max(i + 1);
-                }
-            } else {
-                dp[i + 1][j] = 0;
-            }
-        }
+        //println!("down {:?}", position);
     }
-    let from = index as usize - seq_len as usize;
-    let to = index as usize;
-    let arr2 = &arr[from..to];
-    (arr2, from)
-}
-
-fn find_pattern(arr: &[usize]) -> &[usize] {
-    let mut dp = arr.iter()
-        .map(|_| 0isize)
-        .collect_vec();
-    for i in 1..dp.len() {
-        let mut k = dp[i - 1];
-        let mut done = false;
-        while !done {
-            if arr[i] == arr[k as usize] {
-                dp[i] = k + 1;
-                done = true;
-            } else if k == 0 {
-                dp[i] = 0;
-                done = true;
-            } else {
-                k = dp[k as usize - 1];
-            }
-        }
-    }
-    &arr[0..arr.len() - dp.last().unwrap().clone() as usize]
 }
 
 #[derive(Copy, Clone, Eq, PartialEq)]
@@ -213,6 +127,10 @@ impl<T: Clone> MyGenerator<T> {
     pub fn new(items: Vec<T>) -> Self {
         Self { items, next: 0 }
     }
+    // the index of the item returned next
+    pub fn position(&self) -> usize {
+        self.next
+    }
     pub fn next(&mut self) -> T {
         let shape = self.items[self.next].clone();
         self.next = (self.next + 1) % self.items.len();
@@ -291,7 +209,16 @@ impl Chamber {
             .for_each(|p| {
                 self.data.insert((at.0 + p.0, at.1 - p.1));
             });
-        self.height = self.data.iter().map(|(_, y)| *y).max().unwrap();
+        self.height = self.height.max(at.1);
+    }
+
+    // how deep each column reaches below the top, anything further down cannot be hit anymore
+    pub fn skyline(&self) -> Vec<usize> {
+        (1..=self.width)
+            .map(|x| (0..self.height)
+                .find(|depth| self.data.contains(&(x, self.height - depth)))
+                .unwrap_or(self.height))
+            .collect()
     }
 }
 
@@ -327,8 +254,8 @@ impl ToString for Chamber {
     }
 }
 
-pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Vec<Direction> {
-    io::read_puzzle_first_line(scope.day(), puzzle)
+pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Result<Vec<Direction>> {
+    Ok(io::read_puzzle_first_line(scope, puzzle)?
         .chars()
         .into_iter()
         .map(|c| match c {
@@ -336,5 +263,5 @@ pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Vec<Direction> {
             '>' => Direction::Right,
             _ => unreachable!(),
         })
-        .collect()
+        .collect())
 }
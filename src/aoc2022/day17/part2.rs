@@ -1,17 +1,13 @@
-use crate::aoc2022::day17::part1::{build_direction_generator, build_shape_generator, Chamber, parse_input, tower_height};
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use anyhow::Result;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+use crate::aoc2022::day17::part1::{build_direction_generator, build_shape_generator, Chamber, parse_input, tower_height};
+use crate::lib::common::{Answer, PuzzleScope};
 
-fn execute(scope: &PuzzleScope) {
-    let input = parse_input(scope, "puzzle1");
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let input = parse_input(scope, scope.puzzle())?;
     let mut direction_generator = build_direction_generator(input);
     let mut chamber = Chamber::new(7, 0);
     let mut shape_generator = build_shape_generator();
-    let size = tower_height(1_000_000_000_000, &mut chamber, &mut shape_generator, &mut direction_generator);
-    write_solution(&scope, format!("height = {}", size).as_str());
+    let size = tower_height(scope.param("rocks2")?, &mut chamber, &mut shape_generator, &mut direction_generator);
+    Ok(Answer::from(size))
 }
\ No newline at end of file
@@ -4,20 +4,15 @@ use std::fmt::Error;
 use std::ops::{Add, Sub};
 use std::str::FromStr;
 
+use anyhow::Result;
 use itertools::Itertools;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
-use crate::aoc2022::lib::common::{measure_time_and_print, parse_int, PuzzleScope};
-use crate::aoc2022::lib::io;
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use crate::lib::common::{Answer, parse_int, PuzzleScope};
+use crate::lib::io;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
-
-fn execute(scope: &PuzzleScope) {
-    let Problem { blueprints } = io::read_puzzle_as_string(scope.day(), "puzzle1").parse().unwrap();
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let Problem { blueprints } = io::read_puzzle_as_string(scope, scope.puzzle())?.parse()?;
     //println!("{}", blueprints.iter().map(|s| s.to_string()).collect_vec().join("\n"));
     println!("Standard with BFS, some optimizations");
     let result = max_geodes(24, &blueprints);
@@ -29,10 +24,10 @@ fn execute(scope: &PuzzleScope) {
             level
         })
         .sum();
-    write_solution(&scope, format!("quality level = {:?}", ql).as_str());
+    Ok(Answer::from(ql))
 }
 
-#[derive(Eq, PartialEq, Clone)]
+#[derive(Eq, PartialEq, Hash, Clone)]
 pub struct State {
     pub min_left: usize,
     pub ore_robots: usize,
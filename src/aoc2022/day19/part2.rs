@@ -1,30 +1,34 @@
 use std::ops::Sub;
 
+use anyhow::Result;
 use itertools::Itertools;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
-use crate::aoc2022::day19::beam_search;
 use crate::aoc2022::day19::part1::{Blueprint, MaterialAmount, Problem, Robot, State};
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::io;
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use crate::lib::beam_search;
+use crate::lib::beam_search::{BeamSearch, Stats};
+use crate::lib::common::{Answer, PuzzleScope};
+use crate::lib::io;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
-
-fn execute(scope: &PuzzleScope) {
-    let Problem { blueprints } = io::read_puzzle_as_string(scope.day(), "puzzle1").parse().unwrap();
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let Problem { blueprints } = io::read_puzzle_as_string(scope, scope.puzzle())?.parse()?;
     //println!("{}", blueprints.iter().map(|s| s.to_string()).collect_vec().join("\n"));
-    let result = max_geodes_beam(32, &blueprints[0..3], 20000); // 10k too less, 20k enough
+    // only the first three blueprints are left (the example has just two)
+    let result = max_geodes_beam(32, &blueprints[0..blueprints.len().min(3)], 20000); // 10k too less, 20k enough
+    if scope.param("stats").unwrap_or(false) {
+        for (i, max, stats) in &result {
+            println!("Blueprint #{}: max geodes={}, {} nodes expanded, {} duplicates, {} pruned, depth {}",
+                     i, max, stats.expanded, stats.duplicates, stats.pruned, stats.depth);
+        }
+    }
     let product: usize = result
         .iter()
-        .map(|(_, max)| max)
+        .map(|(_, max, _)| max)
         .product();
-    write_solution(&scope, format!("product = {:?}", product).as_str());
+    Ok(Answer::from(product))
 }
 
+#[derive(Clone)]
 struct Node<'a> {
     state: State,
     blueprint: &'a Blueprint,
@@ -38,8 +42,10 @@ impl<'a> Node<'a> {
 
 
 impl<'a> beam_search::Node for Node<'a> {
+    type Key = State;
+
     // compute all possible next states
-    fn children(&self) -> Vec<Self> where Self: Sized {
+    fn children(&self) -> Vec<Self> {
         let mut children = vec!();
 
         if self.state.min_left == 0 {
@@ -166,9 +172,20 @@ impl<'a> beam_search::Node for Node<'a> {
     fn real_score(&self) -> usize {
         self.state.materials.geode
     }
+
+    // the same state is reached by different orders of building
+    fn key(&self) -> Option<State> {
+        Some(self.state.clone())
+    }
+
+    // as if a new geode robot is built every minute left
+    fn upper_bound(&self) -> Option<usize> {
+        let t = self.state.min_left;
+        Some(self.state.materials.geode + self.state.geode_robots * t + t * t.saturating_sub(1) / 2)
+    }
 }
 
-pub fn max_geodes_beam(limit: usize, blueprint_list: &[Blueprint], width: usize) -> Vec<(usize, usize)> {
+pub fn max_geodes_beam(limit: usize, blueprint_list: &[Blueprint], width: usize) -> Vec<(usize, usize, Stats)> {
     let blueprints = blueprint_list.iter().map(|b| b.clone()).collect_vec();
 
     (0..blueprint_list.len())
@@ -188,7 +205,8 @@ pub fn max_geodes_beam(limit: usize, blueprint_list: &[Blueprint], width: usize)
                 &blueprint,
             );
 
-            (i + 1, beam_search::beam_search(source, width))
+            let outcome = BeamSearch::create(width).run(source);
+            (i + 1, outcome.score(), outcome.stats)
         })
         .collect()
 }
\ No newline at end of file
@@ -1,18 +1,13 @@
 use std::cmp::Ordering;
 
+use anyhow::Result;
 use itertools::Itertools;
 
-use crate::aoc2022::lib::common::{measure_time_and_print, parse_int, PuzzleScope};
-use crate::aoc2022::lib::io;
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use crate::lib::common::{Answer, parse_int, PuzzleScope};
+use crate::lib::io;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
-
-fn execute(scope: &PuzzleScope) {
-    let input = parse_input(scope, "puzzle1");
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let input = parse_input(scope, scope.puzzle())?;
     println!("INPUT");
     print_values(&input);
 
@@ -32,14 +27,14 @@ fn execute(scope: &PuzzleScope) {
         })
         .sum();
 
-    write_solution(&scope, format!("sum = {}", sum).as_str());
+    Ok(Answer::from(sum))
 }
 
-pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Vec<isize> {
-    io::read_puzzle_as_list(scope.day(), puzzle)
+pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Result<Vec<isize>> {
+    Ok(io::read_puzzle_as_list(scope, puzzle)?
         .into_iter()
         .map(|s| parse_int(s.as_str()) as isize)
-        .collect_vec()
+        .collect_vec())
 }
 
 pub fn build_list(input: Vec<isize>) -> Vec<(usize, isize)> {
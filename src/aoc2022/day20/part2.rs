@@ -1,18 +1,13 @@
+use anyhow::Result;
 use itertools::Itertools;
 
 use crate::aoc2022::day20::part1::{build_list, do_mixing, parse_input, print_values};
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::style::{write_header, write_solution};
-
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+use crate::lib::common::{Answer, PuzzleScope};
 
 const DECRYPTION_KEY: isize = 811589153;
 
-fn execute(scope: &PuzzleScope) {
-    let input = parse_input(scope, "puzzle1");
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let input = parse_input(scope, scope.puzzle())?;
     println!("INPUT");
     print_values(&input);
 
@@ -38,5 +33,5 @@ fn execute(scope: &PuzzleScope) {
         })
         .sum();
 
-    write_solution(&scope, format!("sum = {}", sum).as_str());
+    Ok(Answer::from(sum))
 }
\ No newline at end of file
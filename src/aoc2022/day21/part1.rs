@@ -3,27 +3,21 @@ use std::fmt::Error;
 use std::io::ErrorKind;
 use std::str::FromStr;
 
+use anyhow::Result;
 use itertools::Itertools;
 
-use crate::aoc2022::lib::common::{measure_time_and_print, parse_int, PuzzleScope};
-use crate::aoc2022::lib::io;
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use crate::lib::common::{Answer, parse_int, PuzzleScope};
+use crate::lib::io;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
-
-fn execute(scope: &PuzzleScope) {
-    let input = parse_input(scope, "puzzle1");
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let input = parse_input(scope, scope.puzzle())?;
     let mut map: HashMap<String, isize> = HashMap::new();
     solve(&input, &mut map).unwrap();
-    write_solution(&scope, format!("number = {}", map.get("root").unwrap()).as_str());
+    Ok(Answer::from(*map.get("root").unwrap()))
 }
 
-pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Vec<Monkey> {
-    let Problem { monkeys } = io::read_puzzle_as_string(scope.day(), puzzle).parse().unwrap();
-    monkeys
+pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Result<Vec<Monkey>> {
+    io::read_puzzle_as(scope, puzzle)
 }
 
 pub fn solve(monkeys: &[Monkey], map: &mut HashMap<String, isize>) -> Result<(), ErrorKind> {
@@ -94,22 +88,6 @@ pub fn solve(monkeys: &[Monkey], map: &mut HashMap<String, isize>) -> Result<(),
     Ok(())
 }
 
-pub struct Problem {
-    pub monkeys: Vec<Monkey>,
-}
-
-impl FromStr for Problem {
-    type Err = Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut monkeys: Vec<Monkey> = vec!();
-        for line in s.lines() {
-            monkeys.push(line.parse()?);
-        }
-        Ok(Problem { monkeys })
-    }
-}
-
 #[derive(Debug, Eq, PartialEq, Hash, Clone)]
 pub struct Monkey {
     pub name: String,
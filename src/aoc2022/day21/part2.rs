@@ -1,18 +1,14 @@
 use std::collections::HashMap;
 
-use crate::aoc2022::day21::part1::{Dependency, Monkey, parse_input};
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use anyhow::{Error, Result};
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+use crate::aoc2022::day21::part1::{Dependency, Monkey, parse_input};
+use crate::lib::common::{Answer, PuzzleScope};
 
-fn execute(scope: &PuzzleScope) {
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
     // path to humn -> for reversed
     // if multiple -> D&C
-    let input = patch_root(parse_input(scope, "puzzle1"));
+    let input = patch_root(parse_input(scope, scope.puzzle())?);
     let mut map: HashMap<String, Monkey> = HashMap::new();
     input.into_iter()
         .for_each(|monkey| {
@@ -21,8 +17,9 @@ fn execute(scope: &PuzzleScope) {
 
     // too high 9780869100463
 
-    let humn = find_r(&mut map, "root");
-    write_solution(&scope, format!("number = {:?}", humn).as_str());
+    let humn = find_r(&mut map, "root")
+        .ok_or_else(|| Error::msg("no value for humn"))?;
+    Ok(Answer::from(humn))
 }
 
 fn compute_reverse_ops(monkeys: &HashMap<String, Monkey>) -> HashMap<String, Monkey> {
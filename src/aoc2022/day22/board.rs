@@ -1,11 +1,14 @@
 use std::collections::hash_map::Iter;
 use std::collections::HashMap;
-use std::ops::Add;
 use std::str::FromStr;
 
 use anyhow::{Error, Result};
 use itertools::Itertools;
 
+use crate::lib::point;
+
+pub type Point2D = point::Point2D<isize>;
+
 #[derive(Debug, Copy, Clone)]
 pub enum Tile {
     Open,
@@ -20,8 +23,8 @@ pub struct Board {
 
 impl Board {
     pub fn new(data: HashMap<Point2D, Tile>) -> Self {
-        let height = data.iter().map(|(Point2D(_, y), _)| *y).max().unwrap();
-        let width = data.iter().map(|(Point2D(x, _), _)| *x).max().unwrap();
+        let height = data.keys().map(|p| p.y).max().unwrap();
+        let width = data.keys().map(|p| p.x).max().unwrap();
         Self { data, height, width }
     }
     pub fn iter(&self) -> Iter<'_, Point2D, Tile> {
@@ -30,20 +33,20 @@ impl Board {
 
     pub fn top_left(&self) -> Point2D {
         let (top_left, _) = self.iter()
-            .sorted_by_key(|(Point2D(x, y), _)| {
-                (self.width * (*y - 1)) + *x
+            .sorted_by_key(|(p, _)| {
+                (self.width * (p.y - 1)) + p.x
             })
             .enumerate()
             .filter(|(i, _)| i == &0)
             .map(|(_, a)| a)
             .next()
             .unwrap();
-        top_left.clone()
+        *top_left
     }
 
     pub fn find_outer_next_up(&self, from: &Point2D) -> Option<Point2D> {
-        for y in (1..=from.1).rev() {
-            let point = Point2D(from.0, y);
+        for y in (1..=from.y).rev() {
+            let point = Point2D::create(from.x, y);
             match self.data.get(&point) {
                 Some(_) => {
                     return Some(point);
@@ -55,8 +58,8 @@ impl Board {
     }
 
     pub fn find_outer_next_down(&self, from: &Point2D) -> Option<Point2D> {
-        for y in from.1..=self.height {
-            let point = Point2D(from.0, y);
+        for y in from.y..=self.height {
+            let point = Point2D::create(from.x, y);
             match self.data.get(&point) {
                 Some(_) => {
                     return Some(point);
@@ -68,8 +71,8 @@ impl Board {
     }
 
     pub fn find_outer_next_left(&self, from: &Point2D) -> Option<Point2D> {
-        for x in (1..=from.0).rev() {
-            let point = Point2D(x, from.1);
+        for x in (1..=from.x).rev() {
+            let point = Point2D::create(x, from.y);
             match self.data.get(&point) {
                 Some(_) => {
                     return Some(point);
@@ -81,8 +84,8 @@ impl Board {
     }
 
     pub fn find_outer_next_right(&self, from: &Point2D) -> Option<Point2D> {
-        for x in from.0..=self.width {
-            let point = Point2D(x, from.1);
+        for x in from.x..=self.width {
+            let point = Point2D::create(x, from.y);
             match self.data.get(&point) {
                 Some(_) => {
                     return Some(point);
@@ -102,8 +105,8 @@ impl FromStr for Board {
         for (y, line) in s.lines().enumerate() {
             for (x, c) in line.chars().enumerate() {
                 match c {
-                    '.' => { data.insert(Point2D(x as isize + 1, y as isize + 1), Tile::Open); }
-                    '#' => { data.insert(Point2D(x as isize + 1, y as isize + 1), Tile::Solid); }
+                    '.' => { data.insert(Point2D::create(x as isize + 1, y as isize + 1), Tile::Open); }
+                    '#' => { data.insert(Point2D::create(x as isize + 1, y as isize + 1), Tile::Solid); }
                     _ => (),
                 }
             }
@@ -117,17 +120,3 @@ impl ToString for Board {
         todo!()
     }
 }
-
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-pub struct Point2D(pub isize, pub isize);
-
-impl Add for Point2D {
-    type Output = Point2D;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Point2D(
-            self.0 + rhs.0,
-            self.1 + rhs.1,
-        )
-    }
-}
\ No newline at end of file
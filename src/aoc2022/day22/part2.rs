@@ -5,27 +5,17 @@ use itertools::Itertools;
 
 use crate::aoc2022::day22::board::{Board, Point2D, Tile};
 use crate::aoc2022::day22::direction::Direction;
-use crate::aoc2022::day22::part1::{Facing, parse_input};
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::style::{write_header, write_solution};
-
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
-
-fn execute(scope: &PuzzleScope) {
-    let (board, directions) = parse_input(scope, "puzzle1").unwrap();
-    let (point, facing) = do_run(&board, 50, &directions).unwrap();
-    let password = (1000 * point.1) + (4 * point.0) + match facing {
-        Facing::Right => 0,
-        Facing::Down => 1,
-        Facing::Left => 2,
-        Facing::Up => 3,
-    };
+use crate::aoc2022::day22::part1::{facing_score, parse_input};
+use crate::lib::common::{Answer, PuzzleScope};
+use crate::lib::point::Facing;
+
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let (board, directions) = parse_input(scope, scope.puzzle())?;
+    let (point, facing) = do_run(&board, scope.param("size")?, &directions)?;
+    let password = (1000 * point.y) + (4 * point.x) + facing_score(facing);
     println!("{:?}", point);
     println!("{:?}", facing);
-    write_solution(&scope, format!("password = {}", password).as_str());
+    Ok(Answer::from(password))
 }
 
 enum Op {
@@ -48,36 +38,21 @@ fn do_run(board: &Board, n: isize, directions: &[Direction]) -> Result<(Point2D,
         let mut steps = 0;
         match direction {
             Direction::Right(v) => {
-                facing = match facing {
-                    Facing::Up => Facing::Right,
-                    Facing::Right => Facing::Down,
-                    Facing::Down => Facing::Left,
-                    Facing::Left => Facing::Up,
-                };
+                facing = facing.turn_right();
                 steps += v;
                 println!("{:?} {:?}", &facing, v);
             }
             Direction::Left(v) => {
-                facing = match facing {
-                    Facing::Up => Facing::Left,
-                    Facing::Right => Facing::Up,
-                    Facing::Down => Facing::Right,
-                    Facing::Left => Facing::Down,
-                };
+                facing = facing.turn_left();
                 steps += v;
                 println!("{:?} {:?}", &facing, v);
             }
         }
-        last_facing_map.insert(pos.clone(), facing.clone());
+        last_facing_map.insert(pos, facing);
 
-        let mut offset = match facing {
-            Facing::Up => Point2D(0, -1),
-            Facing::Right => Point2D(1, 0),
-            Facing::Down => Point2D(0, 1),
-            Facing::Left => Point2D(-1, 0),
-        };
+        let mut offset = facing.offset();
 
-        let mut next = pos + offset.clone();
+        let mut next = pos + offset;
         while steps > 0 {
             println!("? {:?}={:?}", &next, board.data.get(&next));
 
@@ -86,7 +61,7 @@ fn do_run(board: &Board, n: isize, directions: &[Direction]) -> Result<(Point2D,
             for y in 1..=board.height {
                 let str = (1..=board.width).into_iter()
                     .map(|x| {
-                        let p = Point2D(x,y);
+                        let p = Point2D::create(x,y);
                         if let Some(f) = last_facing_map.get(&p) {
                             match f {
                                 Facing::Up => "^",
@@ -112,48 +87,43 @@ fn do_run(board: &Board, n: isize, directions: &[Direction]) -> Result<(Point2D,
              */
 
             if !board.data.contains_key(&next) {
-                match offset.0 {
+                match offset.x {
                     -1 => {
-                        let (p, f) = neighbors.get(&(pos.clone(), facing)).unwrap();
-                        next = p.clone();
-                        facing = f.clone();
+                        let (p, f) = neighbors.get(&(pos, facing)).unwrap();
+                        next = *p;
+                        facing = *f;
                     }
                     1 => {
-                        let (p, f) = neighbors.get(&(pos.clone(), facing)).unwrap();
-                        next = p.clone();
-                        facing = f.clone();
+                        let (p, f) = neighbors.get(&(pos, facing)).unwrap();
+                        next = *p;
+                        facing = *f;
                     }
                     _ => (),
                 }
-                match offset.1 {
+                match offset.y {
                     -1 => {
-                        let (p, f) = neighbors.get(&(pos.clone(), facing)).unwrap();
-                        next = p.clone();
-                        facing = f.clone();
+                        let (p, f) = neighbors.get(&(pos, facing)).unwrap();
+                        next = *p;
+                        facing = *f;
                     }
                     1 => {
-                        let (p, f) = neighbors.get(&(pos.clone(), facing)).unwrap();
-                        next = p.clone();
-                        facing = f.clone();
+                        let (p, f) = neighbors.get(&(pos, facing)).unwrap();
+                        next = *p;
+                        facing = *f;
                     }
                     _ => (),
                 }
             }
 
-            offset = match facing {
-                Facing::Up => Point2D(0, -1),
-                Facing::Right => Point2D(1, 0),
-                Facing::Down => Point2D(0, 1),
-                Facing::Left => Point2D(-1, 0),
-            };
+            offset = facing.offset();
 
             match board.data.get(&next) {
                 Some(Tile::Open) => {
                     println!("p={:?}", &next);
                     steps -= 1;
                     pos = next;
-                    next = pos + offset.clone();
-                    last_facing_map.insert(pos.clone(), facing.clone());
+                    next = pos + offset;
+                    last_facing_map.insert(pos, facing);
                     continue;
                 }
                 Some(Tile::Solid) => {
@@ -164,16 +134,16 @@ fn do_run(board: &Board, n: isize, directions: &[Direction]) -> Result<(Point2D,
         }
     }
 
-    Ok((pos, last_facing_map.get(&pos).unwrap().clone()))
+    Ok((pos, *last_facing_map.get(&pos).unwrap()))
 }
 
 fn build_cube_neighbors(board: &Board, n: isize) -> HashMap<(Point2D, Facing), (Point2D, Facing)> {
-    let all_points = board.data.keys().map(|p| p.clone()).collect_vec();
+    let all_points = board.data.keys().copied().collect_vec();
 
     let all_blocks: Vec<Point2D> = (1..=board.height).step_by(n as usize).into_iter()
         .flat_map(|y| {
             (1..=board.width).step_by(n as usize).into_iter()
-                .map(move |x| Point2D(x, y))
+                .map(move |x| Point2D::create(x, y))
         })
         .filter(|p| all_points.contains(p))
         .collect_vec();
@@ -184,33 +154,33 @@ fn build_cube_neighbors(board: &Board, n: isize) -> HashMap<(Point2D, Facing), (
     for block in &all_blocks {
         println!("block = {:?}", block);
         for (index, veto_blocks, offset_facing, offset_ops, calc_facing, calc_ops) in &cube_ops {
-            let other = block.clone() + Point2D(index.0 * n, index.1 * n);
+            let other = *block + Point2D::create(index.x * n, index.y * n);
             println!("  index = {:?}, => other = {:?} [veto={:?}]", index, &other, veto_blocks);
-            if index.0 == -1 && index.1 == 3 {
+            if index.x == -1 && index.y == 3 {
                 println!();
             }
-            if index.0 == 1 && index.1 == -1 {
+            if index.x == 1 && index.y == -1 {
                 println!();
             }
             if all_blocks.contains(&other) {
-                if veto_blocks.iter().any(|veto| all_blocks.contains(&(block.clone() + Point2D(veto.0 * n, veto.1 * n)))) {
+                if veto_blocks.iter().any(|veto| all_blocks.contains(&(*block + Point2D::create(veto.x * n, veto.y * n)))) {
                     continue;
                 }
                 println!("  y");
                 let block_offsets = (0..n).into_iter()
-                    .map(|x| Point2D(x, 0))
+                    .map(|x| Point2D::create(x, 0))
                     .map(|p| {
                         let mut p = p;
                         for offset_op in offset_ops {
                             match offset_op {
                                 Op::Flip => {
-                                    p = Point2D(p.1, p.0);
+                                    p = Point2D::create(p.y, p.x);
                                 }
                                 Op::RotateX => {
-                                    p = Point2D((n - 1) - p.0, p.1)
+                                    p = Point2D::create((n - 1) - p.x, p.y)
                                 }
                                 Op::RotateY => {
-                                    p = Point2D(p.0, (n - 1) - p.1)
+                                    p = Point2D::create(p.x, (n - 1) - p.y)
                                 }
                                 Op::Same => {}
                             }
@@ -220,19 +190,19 @@ fn build_cube_neighbors(board: &Board, n: isize) -> HashMap<(Point2D, Facing), (
                     .collect_vec();
 
                 let other_offsets = block_offsets.clone().iter()
-                    .map(|p| p.clone())
+                    .copied()
                     .map(|p| {
                         let mut p = p;
                         for offset_op in calc_ops {
                             match offset_op {
                                 Op::Flip => {
-                                    p = Point2D(p.1, p.0);
+                                    p = Point2D::create(p.y, p.x);
                                 }
                                 Op::RotateX => {
-                                    p = Point2D((n - 1) - p.0, p.1)
+                                    p = Point2D::create((n - 1) - p.x, p.y)
                                 }
                                 Op::RotateY => {
-                                    p = Point2D(p.0, (n - 1) - p.1)
+                                    p = Point2D::create(p.x, (n - 1) - p.y)
                                 }
                                 Op::Same => {}
                             }
@@ -242,10 +212,10 @@ fn build_cube_neighbors(board: &Board, n: isize) -> HashMap<(Point2D, Facing), (
                     .collect_vec();
 
                 for i in 0..n as usize {
-                    let block_p = block.clone() + block_offsets[i];
-                    let other_p = other.clone() + other_offsets[i];
-                    let key = (block_p, offset_facing.clone());
-                    let val = (other_p, calc_facing.clone());
+                    let block_p = *block + block_offsets[i];
+                    let other_p = other + other_offsets[i];
+                    let key = (block_p, *offset_facing);
+                    let val = (other_p, *calc_facing);
                     println!("    neighbor: {:?} => {:?}", key.clone(), val.clone());
                     neighbors.insert(key, val);
                 }
@@ -261,7 +231,7 @@ fn build_cube_operations() -> Vec<(Point2D, Vec<Point2D>, Facing, Vec<Op>, Facin
 
     // left
     mm.push((
-        Point2D(-1, 0),
+        Point2D::create(-1, 0),
         vec!(),
         Facing::Left,
         vec!(Op::Flip),
@@ -270,7 +240,7 @@ fn build_cube_operations() -> Vec<(Point2D, Vec<Point2D>, Facing, Vec<Op>, Facin
     ));
     // right
     mm.push((
-        Point2D(1, 0),
+        Point2D::create(1, 0),
         vec!(),
         Facing::Right,
         vec!(Op::Flip, Op::RotateX),
@@ -279,7 +249,7 @@ fn build_cube_operations() -> Vec<(Point2D, Vec<Point2D>, Facing, Vec<Op>, Facin
     ));
     // up
     mm.push((
-        Point2D(0, -1),
+        Point2D::create(0, -1),
         vec!(),
         Facing::Up,
         vec!(Op::Same),
@@ -288,7 +258,7 @@ fn build_cube_operations() -> Vec<(Point2D, Vec<Point2D>, Facing, Vec<Op>, Facin
     ));
     // down
     mm.push((
-        Point2D(0, 1),
+        Point2D::create(0, 1),
         vec!(),
         Facing::Down,
         vec!(Op::RotateY),
@@ -298,16 +268,16 @@ fn build_cube_operations() -> Vec<(Point2D, Vec<Point2D>, Facing, Vec<Op>, Facin
 
 
     mm.push((
-        Point2D(1, 1),
-        vec!(Point2D(1, 0)),
+        Point2D::create(1, 1),
+        vec!(Point2D::create(1, 0)),
         Facing::Right,
         vec!(Op::Flip, Op::RotateX),
         Facing::Down,
         vec!(Op::RotateX, Op::Flip, Op::RotateX),
     ));
     mm.push((
-        Point2D(1, 1),
-        vec!(Point2D(0, 1)),
+        Point2D::create(1, 1),
+        vec!(Point2D::create(0, 1)),
         Facing::Down,
         vec!(Op::RotateY),
         Facing::Right,
@@ -315,16 +285,16 @@ fn build_cube_operations() -> Vec<(Point2D, Vec<Point2D>, Facing, Vec<Op>, Facin
     ));
 
     mm.push((
-        Point2D(-1, -1),
-        vec!(Point2D(-1, 0)),
+        Point2D::create(-1, -1),
+        vec!(Point2D::create(-1, 0)),
         Facing::Left,
         vec!(Op::Flip),
         Facing::Up,
         vec!(Op::Flip, Op::RotateY, Op::RotateX),
     ));
     mm.push((
-        Point2D(-1, -1),
-        vec!(Point2D(0, -1)),
+        Point2D::create(-1, -1),
+        vec!(Point2D::create(0, -1)),
         Facing::Right,
         vec!(Op::Same),
         Facing::Down,
@@ -332,16 +302,16 @@ fn build_cube_operations() -> Vec<(Point2D, Vec<Point2D>, Facing, Vec<Op>, Facin
     ));
 
     mm.push((
-        Point2D(-1, 1),
-        vec!(Point2D(-1, 0)),
+        Point2D::create(-1, 1),
+        vec!(Point2D::create(-1, 0)),
         Facing::Left,
         vec!(Op::Flip),
         Facing::Down,
         vec!(Op::Flip),
     ));
     mm.push((
-        Point2D(-1, 1),
-        vec!(Point2D(0, 1)),
+        Point2D::create(-1, 1),
+        vec!(Point2D::create(0, 1)),
         Facing::Down,
         vec!(Op::RotateY),
         Facing::Left,
@@ -349,16 +319,16 @@ fn build_cube_operations() -> Vec<(Point2D, Vec<Point2D>, Facing, Vec<Op>, Facin
     ));
 
     mm.push((
-        Point2D(1, -1),
-        vec!(Point2D(0, -1)),
+        Point2D::create(1, -1),
+        vec!(Point2D::create(0, -1)),
         Facing::Up,
         vec!(Op::Same),
         Facing::Right,
         vec!(Op::Flip),
     ));
     mm.push((
-        Point2D(1, -1),
-        vec!(Point2D(1, 0)),
+        Point2D::create(1, -1),
+        vec!(Point2D::create(1, 0)),
         Facing::Right,
         vec!(Op::Flip, Op::RotateX),
         Facing::Up,
@@ -366,16 +336,16 @@ fn build_cube_operations() -> Vec<(Point2D, Vec<Point2D>, Facing, Vec<Op>, Facin
     ));
 
     mm.push((
-        Point2D(1, 2),
-        vec!(Point2D(1, 0), Point2D(1, 1), Point2D(1, 1)),
+        Point2D::create(1, 2),
+        vec!(Point2D::create(1, 0), Point2D::create(1, 1), Point2D::create(1, 1)),
         Facing::Right,
         vec!(Op::Flip, Op::RotateX),
         Facing::Left,
         vec!(Op::RotateY),
     ));
     mm.push((
-        Point2D(1, 2),
-        vec!(Point2D(-1, 0), Point2D(0, 1), Point2D(0, 2)),
+        Point2D::create(1, 2),
+        vec!(Point2D::create(-1, 0), Point2D::create(0, 1), Point2D::create(0, 2)),
         Facing::Left,
         vec!(Op::Flip),
         Facing::Right,
@@ -383,16 +353,16 @@ fn build_cube_operations() -> Vec<(Point2D, Vec<Point2D>, Facing, Vec<Op>, Facin
     ));
 
     mm.push((
-        Point2D(1, -2),
-        vec!(Point2D(1, 0), Point2D(1, -1), Point2D(2, -2)),
+        Point2D::create(1, -2),
+        vec!(Point2D::create(1, 0), Point2D::create(1, -1), Point2D::create(2, -2)),
         Facing::Right,
         vec!(Op::Flip, Op::RotateX),
         Facing::Left,
         vec!(Op::RotateX),
     ));
     mm.push((
-        Point2D(1, -2),
-        vec!(Point2D(-1, 0), Point2D(0, -1), Point2D(0, -2)),
+        Point2D::create(1, -2),
+        vec!(Point2D::create(-1, 0), Point2D::create(0, -1), Point2D::create(0, -2)),
         Facing::Left,
         vec!(Op::Flip),
         Facing::Right,
@@ -400,16 +370,16 @@ fn build_cube_operations() -> Vec<(Point2D, Vec<Point2D>, Facing, Vec<Op>, Facin
     ));
 
     mm.push((
-        Point2D(-1, 2),
-        vec!(Point2D(1, 0), Point2D(0, 1), Point2D(0, 2)),
+        Point2D::create(-1, 2),
+        vec!(Point2D::create(1, 0), Point2D::create(0, 1), Point2D::create(0, 2)),
         Facing::Right,
         vec!(Op::Flip, Op::RotateX),
         Facing::Left,
         vec!(Op::RotateY),
     ));
     mm.push((
-        Point2D(-1, 2),
-        vec!(Point2D(-1, 0), Point2D(-1, 1), Point2D(-2, 2)),
+        Point2D::create(-1, 2),
+        vec!(Point2D::create(-1, 0), Point2D::create(-1, 1), Point2D::create(-2, 2)),
         Facing::Left,
         vec!(Op::Flip),
         Facing::Right,
@@ -417,16 +387,16 @@ fn build_cube_operations() -> Vec<(Point2D, Vec<Point2D>, Facing, Vec<Op>, Facin
     ));
 
     mm.push((
-        Point2D(-1, -2),
-        vec!(Point2D(1, 0), Point2D(0, -1), Point2D(0, -2)),
+        Point2D::create(-1, -2),
+        vec!(Point2D::create(1, 0), Point2D::create(0, -1), Point2D::create(0, -2)),
         Facing::Right,
         vec!(Op::Flip, Op::RotateX),
         Facing::Left,
         vec!(Op::RotateY),
     ));
     mm.push((
-        Point2D(-1, -2),
-        vec!(Point2D(-1, 0), Point2D(-1, -1), Point2D(-2, -2)),
+        Point2D::create(-1, -2),
+        vec!(Point2D::create(-1, 0), Point2D::create(-1, -1), Point2D::create(-2, -2)),
         Facing::Left,
         vec!(Op::Flip),
         Facing::Right,
@@ -434,16 +404,16 @@ fn build_cube_operations() -> Vec<(Point2D, Vec<Point2D>, Facing, Vec<Op>, Facin
     ));
 
     mm.push((
-        Point2D(2, 1),
-        vec!(Point2D(0, 1), Point2D(1, 1), Point2D(2, 2)),
+        Point2D::create(2, 1),
+        vec!(Point2D::create(0, 1), Point2D::create(1, 1), Point2D::create(2, 2)),
         Facing::Down,
         vec!(Op::RotateY),
         Facing::Up,
         vec!(Op::RotateX),
     ));
     mm.push((
-        Point2D(2, 1),
-        vec!(Point2D(0, -1), Point2D(1, 0), Point2D(2, 0)),
+        Point2D::create(2, 1),
+        vec!(Point2D::create(0, -1), Point2D::create(1, 0), Point2D::create(2, 0)),
         Facing::Up,
         vec!(Op::Same),
         Facing::Down,
@@ -451,16 +421,16 @@ fn build_cube_operations() -> Vec<(Point2D, Vec<Point2D>, Facing, Vec<Op>, Facin
     ));
 
     mm.push((
-        Point2D(2, -1),
-        vec!(Point2D(0, -1), Point2D(1, -1), Point2D(2, -2)),
+        Point2D::create(2, -1),
+        vec!(Point2D::create(0, -1), Point2D::create(1, -1), Point2D::create(2, -2)),
         Facing::Up,
         vec!(Op::Same),
         Facing::Down,
         vec!(Op::RotateX),
     ));
     mm.push((
-        Point2D(2, -1),
-        vec!(Point2D(0, 1), Point2D(1, 0), Point2D(2, 0)),
+        Point2D::create(2, -1),
+        vec!(Point2D::create(0, 1), Point2D::create(1, 0), Point2D::create(2, 0)),
         Facing::Down,
         vec!(Op::RotateY),
         Facing::Up,
@@ -468,16 +438,16 @@ fn build_cube_operations() -> Vec<(Point2D, Vec<Point2D>, Facing, Vec<Op>, Facin
     ));
 
     mm.push((
-        Point2D(-2, 1),
-        vec!(Point2D(0, -1), Point2D(-1, 0), Point2D(-2, 0)),
+        Point2D::create(-2, 1),
+        vec!(Point2D::create(0, -1), Point2D::create(-1, 0), Point2D::create(-2, 0)),
         Facing::Up,
         vec!(Op::Same),
         Facing::Down,
         vec!(Op::RotateX),
     ));
     mm.push((
-        Point2D(-2, 1),
-        vec!(Point2D(0, 1), Point2D(-1, 1), Point2D(-2, 2)),
+        Point2D::create(-2, 1),
+        vec!(Point2D::create(0, 1), Point2D::create(-1, 1), Point2D::create(-2, 2)),
         Facing::Down,
         vec!(Op::RotateY),
         Facing::Up,
@@ -485,16 +455,16 @@ fn build_cube_operations() -> Vec<(Point2D, Vec<Point2D>, Facing, Vec<Op>, Facin
     ));
 
     mm.push((
-        Point2D(-2, -1),
-        vec!(Point2D(0, 1), Point2D(-1, 0), Point2D(-2, 0)),
+        Point2D::create(-2, -1),
+        vec!(Point2D::create(0, 1), Point2D::create(-1, 0), Point2D::create(-2, 0)),
         Facing::Down,
         vec!(Op::RotateY),
         Facing::Up,
         vec!(Op::RotateX),
     ));
     mm.push((
-        Point2D(-2, -1),
-        vec!(Point2D(0, -1), Point2D(-1, -1), Point2D(-2, -2)),
+        Point2D::create(-2, -1),
+        vec!(Point2D::create(0, -1), Point2D::create(-1, -1), Point2D::create(-2, -2)),
         Facing::Up,
         vec!(Op::Same),
         Facing::Down,
@@ -503,16 +473,16 @@ fn build_cube_operations() -> Vec<(Point2D, Vec<Point2D>, Facing, Vec<Op>, Facin
 
     // 3rd?
     mm.push((
-        Point2D(-1, 3),
-        vec!(Point2D(0, -1), Point2D(-1, 0), Point2D(-2, 2), Point2D(-2, 3)),
+        Point2D::create(-1, 3),
+        vec!(Point2D::create(0, -1), Point2D::create(-1, 0), Point2D::create(-2, 2), Point2D::create(-2, 3)),
         Facing::Up,
         vec!(Op::Same),
         Facing::Right,
         vec!(Op::Flip),
     ));
     mm.push((
-        Point2D(1, -3),
-        vec!(Point2D(-1, 0), Point2D(-1, -1), Point2D(0, -2), Point2D(0, -3)),
+        Point2D::create(1, -3),
+        vec!(Point2D::create(-1, 0), Point2D::create(-1, -1), Point2D::create(0, -2), Point2D::create(0, -3)),
         Facing::Left,
         vec!(Op::Flip),
         Facing::Down,
@@ -520,16 +490,16 @@ fn build_cube_operations() -> Vec<(Point2D, Vec<Point2D>, Facing, Vec<Op>, Facin
     ));
 
     mm.push((
-        Point2D(-2, 3),
-        vec!(Point2D(0, -1), Point2D(1, 0), Point2D(-1, 3), Point2D(-2, 4)),
+        Point2D::create(-2, 3),
+        vec!(Point2D::create(0, -1), Point2D::create(1, 0), Point2D::create(-1, 3), Point2D::create(-2, 4)),
         Facing::Up,
         vec!(Op::Same),
         Facing::Up,
         vec!(Op::Same),
     ));
     mm.push((
-        Point2D(2, -3),
-        vec!(Point2D(0, 1), Point2D(1, 0), Point2D(3, -3), Point2D(2, -4)),
+        Point2D::create(2, -3),
+        vec!(Point2D::create(0, 1), Point2D::create(1, 0), Point2D::create(3, -3), Point2D::create(2, -4)),
         Facing::Down,
         vec!(Op::RotateY),
         Facing::Down,
@@ -1,39 +1,33 @@
-use std::collections::HashMap;
-
+use anyhow::Result;
 use itertools::Itertools;
 
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::io;
-use crate::aoc2022::lib::point::Point2D;
-use crate::aoc2022::lib::style::{write_header, write_solution};
-
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+use crate::lib::common::{Answer, PuzzleScope};
+use crate::lib::grid::SparseGrid;
+use crate::lib::io;
+use crate::lib::point::{Direction, Point2D};
 
-fn execute(scope: &PuzzleScope) {
-    let mut input = parse_input(scope, "puzzle1");
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let mut input = parse_input(scope, scope.puzzle())?;
     play_rounds(&mut input, 10);
 
     println!();
     println!("Final");
     print_map(&input);
 
-    let sum = count_empty(&input);
-    write_solution(&scope, format!("sum = {}", sum).as_str());
+    let sum = input.count_empty();
+    Ok(Answer::from(sum))
 }
 
-fn play_rounds(map: &mut HashMap<Point2D, Elf>, round_limit: usize) {
+fn play_rounds(map: &mut SparseGrid<Elf>, round_limit: usize) {
     println!();
     println!("Initial Round", );
     print_map(map);
 
     let directions = vec!(
-        Direction::North,
-        Direction::South,
-        Direction::West,
-        Direction::East,
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
     );
 
     let mut direction_offset = 0;
@@ -51,18 +45,14 @@ fn play_rounds(map: &mut HashMap<Point2D, Elf>, round_limit: usize) {
     }
 }
 
-pub fn play_round(map: &mut HashMap<Point2D, Elf>, directions: &Vec<Direction>, direction_offset: usize) -> usize {
-    let mut current_positions = vec!();
-    for pos in map.keys().clone() {
-        current_positions.push(pos.clone());
-    }
+pub fn play_round(map: &mut SparseGrid<Elf>, directions: &[Direction], direction_offset: usize) -> usize {
+    let current_positions: Vec<Point2D> = map.points().copied().collect();
 
     // compute target "propose moving"
     for pos in &current_positions {
-        let mut elf = map.get_mut(&pos).unwrap();
-
-        elf.target = None;
-        if pos.adjacents().iter().all(|a| !current_positions.contains(a)) {
+        let mut target = None;
+        if pos.adjacents().iter().all(|a| !map.contains(a)) {
+            map.get_mut(pos).unwrap().target = target;
             continue;
         }
 
@@ -75,41 +65,42 @@ pub fn play_round(map: &mut HashMap<Point2D, Elf>, directions: &Vec<Direction>,
         }
         for opt_dir in dirs {
             match opt_dir {
-                Direction::North => {
+                Direction::Up => {
                     let list = vec![pos.top().left(), pos.top(), pos.top().right()];
-                    if list.iter().all(|a| !current_positions.contains(a)) {
-                        elf.target = Some(pos.top());
+                    if list.iter().all(|a| !map.contains(a)) {
+                        target = Some(pos.top());
                         //println!("{:?}", opt_dir);
                         break;
                     }
                 }
-                Direction::South => {
+                Direction::Down => {
                     let list = vec![pos.bottom().left(), pos.bottom(), pos.bottom().right()];
-                    if list.iter().all(|a| !current_positions.contains(a)) {
-                        elf.target = Some(pos.bottom());
+                    if list.iter().all(|a| !map.contains(a)) {
+                        target = Some(pos.bottom());
                         //println!("{:?}", opt_dir);
                         break;
                     }
                 }
-                Direction::West => {
+                Direction::Left => {
                     let list = vec![pos.left().top(), pos.left(), pos.left().bottom()];
-                    if list.iter().all(|a| !current_positions.contains(a)) {
-                        elf.target = Some(pos.left());
+                    if list.iter().all(|a| !map.contains(a)) {
+                        target = Some(pos.left());
                         //println!("{:?}", opt_dir);
                         break;
                     }
                 }
-                Direction::East => {
+                Direction::Right => {
                     let list = vec![pos.right().top(), pos.right(), pos.right().bottom()];
-                    if list.iter().all(|a| !current_positions.contains(a)) {
-                        elf.target = Some(pos.right());
+                    if list.iter().all(|a| !map.contains(a)) {
+                        target = Some(pos.right());
                         //println!("{:?}", opt_dir);
                         break;
                     }
                 }
             }
         }
-        //println!("{:?} => {:?}", pos, elf.target);
+        //println!("{:?} => {:?}", pos, target);
+        map.get_mut(pos).unwrap().target = target;
     }
 
     let movable = map.iter()
@@ -138,51 +129,22 @@ pub fn play_round(map: &mut HashMap<Point2D, Elf>, directions: &Vec<Direction>,
     cnt
 }
 
-pub fn print_map(map: &HashMap<Point2D, Elf>) {
-    let min_x = map.keys().map(|p| p.x()).min().unwrap() - 1;
-    let max_x = map.keys().map(|p| p.x()).max().unwrap() + 1;
-    let min_y = map.keys().map(|p| p.y()).min().unwrap() - 1;
-    let max_y = map.keys().map(|p| p.y()).max().unwrap() + 1;
+pub fn print_map(map: &SparseGrid<Elf>) {
+    let (min, max) = map.bounds().unwrap();
+    let min = Point2D::create(min.x() - 1, min.y() - 1);
+    let max = Point2D::create(max.x() + 1, max.y() + 1);
 
-    let str0 = (min_x..=max_x).into_iter()
+    let str0 = (min.x()..=max.x())
         .map(|d| d % 10)
         .map(|d| format!("{}", d))
         .join("");
     println!("{}", str0);
-    for y in min_y..=max_y {
-        let str = (min_x..=max_x).into_iter()
-            .map(|x| {
-                if map.contains_key(&Point2D::create(x, y)) {
-                    "#"
-                } else {
-                    "."
-                }
-            })
-            .join("");
-        println!("{}", str)
-    }
+    print!("{}", map.render_region(min, max, |elf| if elf.is_some() { '#' } else { '.' }));
 }
 
-fn count_empty(map: &HashMap<Point2D, Elf>) -> usize {
-    let min_x = map.keys().map(|p| p.x()).min().unwrap();
-    let max_x = map.keys().map(|p| p.x()).max().unwrap();
-    let min_y = map.keys().map(|p| p.y()).min().unwrap();
-    let max_y = map.keys().map(|p| p.y()).max().unwrap();
-
-    (min_y..=max_y).into_iter()
-        .map(|y| {
-            (min_x..=max_x).into_iter()
-                .filter(|x| {
-                    !map.contains_key(&Point2D::create(*x, y))
-                })
-                .count()
-        })
-        .sum()
-}
-
-pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> HashMap<Point2D, Elf> {
-    let mut map = HashMap::new();
-    io::read_puzzle_as_list(scope.day(), puzzle).iter()
+pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Result<SparseGrid<Elf>> {
+    let mut map = SparseGrid::new();
+    io::read_puzzle_as_list(scope, puzzle)?.iter()
         .enumerate()
         .for_each(|(i, line)| {
             line.chars().into_iter()
@@ -194,15 +156,7 @@ pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> HashMap<Point2D, Elf> {
                     }
                 })
         });
-    map
-}
-
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
-pub enum Direction {
-    North,
-    East,
-    South,
-    West,
+    Ok(map)
 }
 
 pub struct Elf {
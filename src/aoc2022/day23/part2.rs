@@ -1,17 +1,12 @@
-use std::collections::HashMap;
+use anyhow::Result;
 
-use crate::aoc2022::day23::part1::{Direction, Elf, parse_input, play_round, print_map};
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::point::Point2D;
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use crate::aoc2022::day23::part1::{Elf, parse_input, play_round, print_map};
+use crate::lib::common::{Answer, PuzzleScope};
+use crate::lib::grid::SparseGrid;
+use crate::lib::point::Direction;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
-
-fn execute(scope: &PuzzleScope) {
-    let mut input = parse_input(scope, "puzzle1");
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let mut input = parse_input(scope, scope.puzzle())?;
 
     let rounds = play_rounds_until_lms(&mut input);
 
@@ -19,19 +14,19 @@ fn execute(scope: &PuzzleScope) {
     println!("Final");
     print_map(&input);
 
-    write_solution(&scope, format!("rounds = {}", rounds).as_str());
+    Ok(Answer::from(rounds))
 }
 
-fn play_rounds_until_lms(map: &mut HashMap<Point2D, Elf>) -> usize {
+fn play_rounds_until_lms(map: &mut SparseGrid<Elf>) -> usize {
     println!();
     println!("Initial Round", );
     print_map(map);
 
     let directions = vec!(
-        Direction::North,
-        Direction::South,
-        Direction::West,
-        Direction::East,
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
     );
 
     let mut direction_offset = 0;
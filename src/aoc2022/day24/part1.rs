@@ -1,57 +1,63 @@
-use std::cmp::Ordering;
-use std::collections::{HashMap, HashSet, VecDeque};
 use std::usize;
 
 use anyhow::{Error, Result};
 use itertools::Itertools;
 use rayon::prelude::*;
 
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::io;
-use crate::aoc2022::lib::point::Point2D;
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use crate::lib::common::{Answer, PuzzleScope};
+use crate::lib::grid::Grid2D;
+use crate::lib::image::{BLACK, FrameRecorder, GRAY, Image, RED, WHITE};
+use crate::lib::io;
+use crate::lib::point::{Direction, Point2D};
+use crate::lib::search::{astar, SearchSpace};
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
-
-fn execute(scope: &PuzzleScope) {
-    let input = parse_input(scope, "puzzle1");
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let input = parse_input(scope, scope.puzzle())?;
 
     println!("Initial");
     print_map(&input, 0, &input.start);
 
-    let result = shortest_path(&input, &input.start, &input.end, 0).unwrap();
-    write_solution(&scope, format!("result = {:?}", result).as_str());
+    let (result, path) = shortest_path(&input, &input.start, &input.end, 0)?;
+    record_blizzards(scope, &input, &path)?;
+    Ok(Answer::from(result))
 }
 
-#[derive(Debug, Eq, PartialEq)]
-struct State(Point2D, usize, usize);
-
-impl PartialOrd<Self> for State {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+// the valley for each minute of the walk, with the expedition at the position of that minute
+pub fn record_blizzards(scope: &PuzzleScope, input: &Input, path: &[Point2D]) -> Result<()> {
+    let mut recorder = FrameRecorder::for_scope(scope, "blizzards")?.scale(4);
+    for (minute, pos) in path.iter().enumerate() {
+        recorder.record(|| blizzards_to_image(input, minute, pos))?;
     }
+    Ok(())
 }
 
-impl Ord for State {
-    fn cmp(&self, other: &Self) -> Ordering {
-        let by_min = self.2.cmp(&other.2);
-        let by_dist = self.1.cmp(&other.1);
-        by_min.then(by_dist.reverse())
-    }
+pub fn blizzards_to_image(input: &Input, minute: usize, current: &Point2D) -> Image {
+    let blizzards = build_blizzard_grid(input, minute % input.timing_levels());
+    let max = Point2D::create(blizzards.width(), blizzards.height());
+    Image::render(Point2D::create(-1, -1), max, |p| {
+        if p == *current {
+            RED
+        } else if p == input.start || p == input.end {
+            BLACK
+        } else {
+            match blizzards.get(p.x(), p.y()) {
+                None => GRAY,
+                Some(true) => WHITE,
+                Some(false) => BLACK,
+            }
+        }
+    })
 }
 
 pub fn print_map(input: &Input, timing_level: usize, current: &Point2D) {
     let blizzards = get_blizzard_state(input, timing_level);
-    for y in -1..=input.height as i32 {
-        if y == -1 || y == input.height as i32 {
-            println!("{}", "#".repeat(input.width + 2));
+    for y in -1..=input.valley.height() {
+        if y == -1 || y == input.valley.height() {
+            println!("{}", "#".repeat(input.valley.width() as usize + 2));
         } else {
             let mut str_items: Vec<String> = vec!();
-            for x in 0..input.width {
-                let p = Point2D::create(x as i32, y);
+            for x in 0..input.valley.width() {
+                let p = Point2D::create(x, y);
                 if p.eq(current) {
                     str_items.push("E".to_owned());
                 } else {
@@ -80,117 +86,58 @@ pub fn print_map(input: &Input, timing_level: usize, current: &Point2D) {
     }
 }
 
-pub fn shortest_path(input: &Input, start: &Point2D, goal: &Point2D, time_offset: usize) -> Result<usize> {
-    let max_timing_levels = input.height * input.width;
+// the minute of arrival and the position for each minute from `time_offset` on
+pub fn shortest_path(input: &Input, start: &Point2D, goal: &Point2D, time_offset: usize) -> Result<(usize, Vec<Point2D>)> {
+    let max_timing_levels = input.timing_levels();
 
     println!("Warming up...");
 
-    // prepare: manhatten distances from each point to end (for each timing_level)
-    let mut mhd = HashMap::new();
-    for x in 0..input.width {
-        for y in 0..input.width {
-            let p = Point2D::create(x as i32, y as i32);
-            mhd.insert(p, p.manhatten_distance(&goal));
-        }
-    }
-    mhd.insert(start.clone(), start.clone().manhatten_distance(&goal));
-    mhd.insert(goal.clone(), 0);
-
     // prepare: blizzard positions for each timing_level
     // layout of positions will repeat after H*W
-    let mut ti_blizzard_positions = HashMap::new();
-    {
-        let items: Vec<_> = (0..max_timing_levels).into_par_iter()
-            .map(|i| (i, build_blizzard_state_set(input, i)))
-            .collect();
-        items.into_iter().for_each(|(i, hs)| {
-            ti_blizzard_positions.insert(i, hs);
-        });
-    }
-
-    // is_in_grid fn utility
-    let range_x = 0..input.width as i32;
-    let range_y = 0..input.height as i32;
-    let is_in_grid = |p: &Point2D| -> bool {
-        range_x.contains(&p.x()) && range_y.contains(&p.y())
-    };
+    let blizzards: Vec<Grid2D<bool>> = (0..max_timing_levels).into_par_iter()
+        .map(|i| build_blizzard_grid(input, i))
+        .collect();
 
     println!("Warming completed!");
     println!();
 
-    let mut q = VecDeque::new();
-    let mut visited: HashSet<(usize, Point2D)> = HashSet::new();
-    q.push_back(State(start.clone(), 0, time_offset));
-
-    let mut minimum: Option<usize> = None;
-
-    while let Some(State(pos, dist, minute)) = q.pop_front() {
-        /*
-        println!();
-        println!("Minute {minute}");
-        print_map(&input, minute % (input.height * input.width), &pos);
-         */
-
-        if &pos == goal {
-            match minimum {
-                Some(min_minute) => {
-                    if minute < min_minute {
-                        println!("Found better minimum, {min_minute} => {minute} ∂={dist}");
-                        minimum = Some(minute);
-                    }
-                }
-                None => {
-                    println!("Found first minimum at {minute} ∂={dist}");
-                    minimum = Some(minute);
-                }
-            }
-            continue;
-        }
+    let valley = Valley { blizzards, start: *start, goal: *goal };
+    let found = astar(&valley, (*start, time_offset % max_timing_levels))
+        .ok_or_else(|| Error::msg("no minimum found"))?;
+    let minute = time_offset + found.cost;
+    println!("Found minimum at {minute} ∂={}", found.cost);
+    Ok((minute, found.path.iter().map(|(pos, _)| *pos).collect()))
+}
 
-        let next_minute = minute + 1;
+// a state is the position at a timing level, as the blizzards repeat after that
+struct Valley {
+    blizzards: Vec<Grid2D<bool>>,
+    start: Point2D,
+    goal: Point2D,
+}
 
-        let mut next_candidates = vec!();
-        vec!((1, 0), (0, 1), (-1, 0), (0, -1), (0, 0)).into_iter()
-            .for_each(|offset| {
-                next_candidates.push(Point2D::create(pos.x() + offset.0, pos.y() + offset.1));
-            });
+impl SearchSpace for Valley {
+    type State = (Point2D, usize);
 
-        for a in next_candidates {
+    fn neighbours(&self, (pos, level): &(Point2D, usize)) -> Vec<((Point2D, usize), usize)> {
+        let next_level = (level + 1) % self.blizzards.len();
+        let blizzards = &self.blizzards[next_level];
+        Direction::ALL.iter().map(|d| *pos + d.offset())
+            .chain([*pos])
             // valid in this grid?
-            if !is_in_grid(&a) && !goal.eq(&a) && !start.eq(&a) {
-                continue;
-            }
+            .filter(|a| blizzards.contains(a.x(), a.y()) || a == &self.goal || a == &self.start)
             // not blocked by a blizzard?
-            if ti_blizzard_positions[&((next_minute) % max_timing_levels)].contains(&a) {
-                continue;
-            }
-            // reachable within less than the known minimum?
-            match minimum {
-                Some(min_minute) => {
-                    if next_minute + mhd[&a] > min_minute {
-                        continue;
-                    }
-                }
-                None => {}
-            }
-            // already visited
-            if visited.contains(&(next_minute, a.clone())) {
-                continue;
-            }
+            .filter(|a| blizzards.get(a.x(), a.y()) != Some(&true))
+            .map(|a| ((a, next_level), 1))
+            .collect()
+    }
 
-            q.push_back(State(a, dist + 1, next_minute));
-            visited.insert((next_minute, a.clone()));
-        }
+    fn is_goal(&self, (pos, _): &(Point2D, usize)) -> bool {
+        pos == &self.goal
     }
 
-    // position within the block, so we need one more
-    match minimum {
-        Some(minute) => {
-            Ok(minute as usize)
-        }
-        None => {
-            Err(Error::msg("no minimum found"))
-        }
+    fn heuristic(&self, (pos, _): &(Point2D, usize)) -> usize {
+        pos.manhatten_distance(&self.goal)
     }
 }
 
@@ -199,10 +146,10 @@ fn get_blizzard_state(input: &Input, n: usize) -> Vec<(Point2D, Point2D)> {
         .map(|blizzard| {
             let mut p = blizzard.start.clone();
             for _ in 0..n {
-                p = p + blizzard.offset;
+                p += blizzard.offset;
             }
-            let px = p.x().rem_euclid(input.width as i32);
-            let py = p.y().rem_euclid(input.height as i32);
+            let px = p.x().rem_euclid(input.valley.width());
+            let py = p.y().rem_euclid(input.valley.height());
             (Point2D::create(
                 px,
                 py,
@@ -211,70 +158,66 @@ fn get_blizzard_state(input: &Input, n: usize) -> Vec<(Point2D, Point2D)> {
         .collect_vec()
 }
 
-fn build_blizzard_state_set(input: &Input, n: usize) -> HashSet<Point2D> {
-    let mut hs = HashSet::new();
-    input.blizzards.iter()
-        .for_each(|blizzard| {
-            let p = Point2D::create(
-                (blizzard.start.x + blizzard.offset.x * (n as i32)).rem_euclid(input.width as i32),
-                (blizzard.start.y + blizzard.offset.y * (n as i32)).rem_euclid(input.height as i32),
-            );
-            assert!(0 <= p.x && p.x < input.width as i32, "x out of range: {:?}", p);
-            assert!(0 <= p.y && p.y < input.height as i32, "y out of range: {:?}", p);
-            hs.insert(p);
-        });
-    hs
+// the cells covered by a blizzard at minute `n`
+fn build_blizzard_grid(input: &Input, n: usize) -> Grid2D<bool> {
+    let (width, height) = (input.valley.width(), input.valley.height());
+    let mut grid = Grid2D::create(height, width, false);
+    for blizzard in &input.blizzards {
+        let x = (blizzard.start.x + blizzard.offset.x * (n as i32)).rem_euclid(width);
+        let y = (blizzard.start.y + blizzard.offset.y * (n as i32)).rem_euclid(height);
+        grid.set(x, y, true);
+    }
+    grid
 }
 
 pub struct Input {
-    pub height: usize,
-    pub width: usize,
+    // the area within the walls, as it is at the start
+    pub valley: Grid2D<char>,
     pub start: Point2D,
     pub end: Point2D,
     pub blizzards: Vec<Blizzard>,
 }
 
+impl Input {
+    // the blizzards repeat after this many minutes
+    pub fn timing_levels(&self) -> usize {
+        self.valley.size() as usize
+    }
+}
+
 pub struct Blizzard {
     pub start: Point2D,
     pub offset: Point2D,
 }
 
-pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Input {
-    let lines = io::read_puzzle_as_list(scope.day(), puzzle);
-    let height = lines.len() - 2;
-    let width = lines.last().unwrap().len() - 2;
-    let start = Point2D::create(
-        lines[0].chars().enumerate().find(|(_, c)| c == &'.').map(|(i, _)| i).unwrap() as i32 - 1,
-        -1,
-    );
-    let end = Point2D::create(
-        lines.last().unwrap().chars().enumerate().find(|(_, c)| c == &'.').map(|(i, _)| i).unwrap() as i32 - 1,
-        height as i32, // +1
-    );
-
-    let blizzards = lines.iter().enumerate().filter(|(i, _)| (1..=height).contains(i))
-        .flat_map(|(j, line)| {
-            let y = j.clone() as i32 - 1;
-            line.chars().enumerate()
-                .filter(|(j, _)| (1..=width).contains(&j))
-                .flat_map(move |(i, c)| {
-                    let x = i.clone() as i32 - 1;
-                    match c {
-                        '>' => vec!(Blizzard { start: Point2D::create(x, y), offset: Point2D::create(1, 0) }),
-                        '<' => vec!(Blizzard { start: Point2D::create(x, y), offset: Point2D::create(-1, 0) }),
-                        'v' => vec!(Blizzard { start: Point2D::create(x, y), offset: Point2D::create(0, 1) }),
-                        '^' => vec!(Blizzard { start: Point2D::create(x, y), offset: Point2D::create(0, -1) }),
-                        '.' => vec!(),
-                        _ => unreachable!(),
-                    }
-                })
-        })
-        .collect_vec();
-    Input {
-        height,
-        width,
+pub fn parse_input(scope: &PuzzleScope, puzzle: &str) -> Result<Input> {
+    let map = io::read_puzzle_as_char_grid(scope, puzzle)?;
+    let (width, height) = (map.width() - 2, map.height() - 2);
+    let valley = Grid2D::from_rows(
+        (1..=height).map(|y| map.row(y).skip(1).take(width as usize).copied().collect()).collect()
+    )?;
+    let opening = |y: i32| map.row(y).position(|c| *c == '.')
+        .map(|x| x as i32 - 1)
+        .ok_or_else(|| Error::msg(format!("no opening in row {}", y + 1)));
+    let start = Point2D::create(opening(0)?, -1);
+    let end = Point2D::create(opening(height + 1)?, height);
+
+    let mut blizzards = vec!();
+    for (p, c) in valley.cells() {
+        let offset = match c {
+            '>' => Point2D::create(1, 0),
+            '<' => Point2D::create(-1, 0),
+            'v' => Point2D::create(0, 1),
+            '^' => Point2D::create(0, -1),
+            '.' => continue,
+            _ => return Err(Error::msg(format!("unexpected '{}' at {:?}", c, p))),
+        };
+        blizzards.push(Blizzard { start: p, offset });
+    }
+    Ok(Input {
+        valley,
         start,
         end,
         blizzards,
-    }
+    })
 }
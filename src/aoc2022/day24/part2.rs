@@ -1,23 +1,25 @@
-use crate::aoc2022::day24::part1::{parse_input, print_map, shortest_path};
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use anyhow::Result;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+use crate::aoc2022::day24::part1::{parse_input, print_map, record_blizzards, shortest_path};
+use crate::lib::common::{Answer, PuzzleScope};
 
-fn execute(scope: &PuzzleScope) {
-    let input = parse_input(scope, "puzzle1");
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    let input = parse_input(scope, scope.puzzle())?;
 
     println!("Initial");
     print_map(&input, 0, &input.start);
 
     // start -> goal
-    let mut result = shortest_path(&input, &input.start, &input.end, 0).unwrap();
+    let (mut result, mut path) = shortest_path(&input, &input.start, &input.end, 0)?;
     // goal -> start
-    result = shortest_path(&input, &input.end, &input.start, result).unwrap();
+    let (minute, back) = shortest_path(&input, &input.end, &input.start, result)?;
+    result = minute;
+    // each leg starts where the previous one ended
+    path.extend(back.into_iter().skip(1));
     // start -> goal
-    result = shortest_path(&input, &input.start, &input.end, result).unwrap();
-    write_solution(&scope, format!("result = {:?}", result).as_str());
+    let (minute, again) = shortest_path(&input, &input.start, &input.end, result)?;
+    result = minute;
+    path.extend(again.into_iter().skip(1));
+    record_blizzards(scope, &input, &path)?;
+    Ok(Answer::from(result))
 }
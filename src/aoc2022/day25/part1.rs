@@ -1,21 +1,17 @@
-use crate::aoc2022::lib::common::{measure_time_and_print, PuzzleScope};
-use crate::aoc2022::lib::io;
-use crate::aoc2022::lib::style::{write_header, write_solution};
+use anyhow::Result;
 
-pub fn run(scope: PuzzleScope) {
-    write_header(&scope);
-    measure_time_and_print(&scope, execute);
-}
+use crate::lib::common::{Answer, PuzzleScope};
+use crate::lib::io;
 
-fn execute(scope: &PuzzleScope) {
-    write_solution(&scope, format!("sum = {}", convert_dec2snafu(convert_and_sum(scope, "puzzle1"))).as_str());
+pub fn run(scope: &PuzzleScope) -> Result<Answer> {
+    Ok(Answer::from(convert_dec2snafu(convert_and_sum(scope, scope.puzzle())?)))
 }
 
-fn convert_and_sum(scope: &PuzzleScope, puzzle: &str) -> isize {
-    io::read_puzzle_as_list(scope.day(), puzzle)
+fn convert_and_sum(scope: &PuzzleScope, puzzle: &str) -> Result<isize> {
+    Ok(io::read_puzzle_as_list(scope, puzzle)?
         .iter()
         .map(|s| convert_snafu2dec(s))
-        .sum()
+        .sum())
 }
 
 fn convert_snafu2dec(str: &str) -> isize {
@@ -1,11 +0,0 @@
-pub mod common;
-#[allow(dead_code)]
-pub mod io;
-#[allow(dead_code)]
-pub mod grid;
-#[allow(dead_code)]
-pub mod point;
-pub mod style;
-
-#[cfg(test)]
-mod tests;
\ No newline at end of file
@@ -1 +0,0 @@
-mod grid;
\ No newline at end of file
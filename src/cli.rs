@@ -0,0 +1,137 @@
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use anyhow::{Error, Result};
+use clap::{Args, Parser, Subcommand};
+
+use crate::lib::common::{InputSource, Variant};
+
+/// Runner for the Advent Of Code solutions
+#[derive(Parser)]
+#[command(name = "aoc2022", version)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Run the selected puzzles
+    Run(Filter),
+    /// Run every registered puzzle
+    RunAll(RunAllArgs),
+    /// List all registered puzzles
+    List,
+    /// Run the selected puzzles repeatedly and report timing statistics
+    Bench(BenchArgs),
+}
+
+#[derive(Args)]
+pub struct Filter {
+    /// Years to select, e.g. `2022` (default: all)
+    #[arg(short, long)]
+    pub year: Option<Selection>,
+    /// Days to select, e.g. `1`, `1-5` or `1,3,10-12` (default: all)
+    #[arg(short, long)]
+    pub day: Option<Selection>,
+    /// Parts to select, e.g. `1` or `1,2` (default: all)
+    #[arg(short, long)]
+    pub part: Option<Selection>,
+    /// Read the puzzle input from this file instead of `puzzles/<year>/dayNN`, `-` reads from stdin
+    #[arg(short, long)]
+    pub input: Option<InputSource>,
+    /// Solve the example of the puzzle text (`puzzle0`) instead of the real input
+    #[arg(short, long)]
+    pub example: bool,
+    /// Dump the frames of simulations as PNG images into this directory
+    #[arg(long)]
+    pub frames: Option<PathBuf>,
+    /// Record only every n-th simulation step as a frame
+    #[arg(long, default_value_t = 1)]
+    pub frame_step: usize,
+}
+
+impl Filter {
+    pub fn variant(&self) -> Variant {
+        if self.example { Variant::Example } else { Variant::Real }
+    }
+
+    pub fn matches(&self, year: i32, day: i32, part: i32) -> bool {
+        let year_matches = self.year.as_ref().is_none_or(|s| s.contains(year));
+        let day_matches = self.day.as_ref().is_none_or(|s| s.contains(day));
+        let part_matches = self.part.as_ref().is_none_or(|s| s.contains(part));
+        year_matches && day_matches && part_matches
+    }
+}
+
+#[derive(Args)]
+pub struct RunAllArgs {
+    /// Solve the examples of the puzzle texts (`puzzle0`) instead of the real inputs
+    #[arg(short, long)]
+    pub example: bool,
+}
+
+#[derive(Args)]
+pub struct BenchArgs {
+    #[command(flatten)]
+    pub filter: Filter,
+    /// Number of measured runs per puzzle
+    #[arg(short, long, default_value_t = 10)]
+    pub runs: usize,
+    /// Number of unmeasured runs per puzzle before measuring
+    #[arg(short, long, default_value_t = 2)]
+    pub warmup: usize,
+    /// Save the results as JSON to this file
+    #[arg(long)]
+    pub save: Option<PathBuf>,
+    /// Compare the results with a previously saved JSON file
+    #[arg(long)]
+    pub compare: Option<PathBuf>,
+}
+
+/// A list of numbers and number ranges like `1,3,10-12`
+#[derive(Clone, Debug)]
+pub struct Selection {
+    ranges: Vec<RangeInclusive<i32>>,
+}
+
+impl Selection {
+    pub fn contains(&self, value: i32) -> bool {
+        self.ranges.iter().any(|r| r.contains(&value))
+    }
+}
+
+impl FromStr for Selection {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut ranges = vec!();
+        for item in s.split(',') {
+            let range = match item.split_once('-') {
+                Some((from, to)) => from.trim().parse()?..=to.trim().parse()?,
+                None => {
+                    let value = item.trim().parse()?;
+                    value..=value
+                }
+            };
+            if range.is_empty() {
+                return Err(Error::msg(format!("invalid range '{item}'")));
+            }
+            ranges.push(range);
+        }
+        Ok(Selection { ranges })
+    }
+}
+
+#[test]
+fn test_selection() {
+    let selection: Selection = "1,3,10-12".parse().unwrap();
+    assert!(selection.contains(1));
+    assert!(!selection.contains(2));
+    assert!(selection.contains(3));
+    assert!(selection.contains(11));
+    assert!(!selection.contains(13));
+    assert!("5-3".parse::<Selection>().is_err());
+    assert!("x".parse::<Selection>().is_err());
+}
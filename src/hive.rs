@@ -0,0 +1,44 @@
+use std::collections::BTreeMap;
+
+use crate::lib::common::Solution;
+
+pub struct Hive {
+    data: BTreeMap<(i32, i32, i32), Solution>,
+}
+
+impl Hive {
+    pub fn create() -> Hive {
+        Hive {
+            data: BTreeMap::new(),
+        }
+    }
+
+    pub fn register(&mut self, year: i32, day: i32, part: i32, cb: Solution) {
+        self.data.insert((year, day, part), cb);
+    }
+
+    pub fn lookup(&self, year: i32, day: i32, part: i32) -> Option<&Solution> {
+        self.data.get(&(year, day, part))
+    }
+
+    // all registered puzzles, ordered by year, day and part
+    pub fn entries(&self) -> Vec<(i32, i32, i32)> {
+        self.data.keys().copied().collect()
+    }
+}
+
+// declares the day modules of a year and a `register` fn adding all of their parts to a hive,
+// e.g. `3 => day03 [1 => part1, 2 => part2]` registers `day03::part1::run` as day 3 part 1 and so on
+macro_rules! puzzles {
+    ($year:literal; $($day:literal => $module:ident [$($part:literal => $run:ident),+]),+ $(,)?) => {
+        $(pub mod $module;)+
+
+        pub fn register(hive: &mut $crate::hive::Hive) {
+            $($(
+                hive.register($year, $day, $part, $module::$run::run);
+            )+)+
+        }
+    };
+}
+
+pub(crate) use puzzles;
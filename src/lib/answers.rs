@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+use std::fs;
+
+use anyhow::{Error, Result};
+
+use crate::lib::common::{Answer, InputSource, PuzzleScope};
+use crate::lib::io;
+
+// expected answers of a day, stored in `puzzles/<year>/dayNN/answers`
+// every line is `<part> <puzzle> <answer>` like `1 puzzle1 70613`, `#` starts a comment
+pub struct Answers {
+    data: HashMap<(i32, String), String>,
+}
+
+impl Answers {
+    pub fn parse(s: &str) -> Result<Answers> {
+        let mut data = HashMap::new();
+        for (idx, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut split = line.splitn(3, ' ');
+            match (split.next(), split.next(), split.next()) {
+                (Some(part), Some(puzzle), Some(answer)) => {
+                    let part: i32 = part.parse()
+                        .map_err(|_| Error::msg(format!("line {}: invalid part '{}'", idx + 1, part)))?;
+                    data.insert((part, puzzle.to_string()), answer.trim().to_string());
+                }
+                _ => return Err(Error::msg(format!("line {}: expected '<part> <puzzle> <answer>'", idx + 1))),
+            }
+        }
+        Ok(Answers { data })
+    }
+
+    // a day without an answers file simply has no expectations
+    pub fn load(scope: &PuzzleScope) -> Result<Answers> {
+        let path = io::day_path(scope, "answers");
+        if !path.exists() {
+            return Ok(Answers { data: HashMap::new() });
+        }
+        Answers::parse(fs::read_to_string(&path)?.as_str())
+            .map_err(|e| Error::msg(format!("{}: {}", path.display(), e)))
+    }
+
+    pub fn expected(&self, part: i32, puzzle: &str) -> Option<&str> {
+        self.data.get(&(part, puzzle.to_string())).map(|s| s.as_str())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong(String),
+    Unknown,
+}
+
+// compares an answer with the recorded one of the puzzle file the solution has read
+pub fn verify(scope: &PuzzleScope, answer: &Answer) -> Result<Verdict> {
+    // a custom input has no recorded answer
+    if scope.source() != &InputSource::Default {
+        return Ok(Verdict::Unknown);
+    }
+    let puzzle = match scope.loaded() {
+        Some(puzzle) => puzzle,
+        None => return Ok(Verdict::Unknown),
+    };
+    let answers = Answers::load(scope)?;
+    Ok(match answers.expected(scope.part(), puzzle.as_str()) {
+        Some(expected) if expected == answer.to_string().trim() => Verdict::Correct,
+        Some(expected) => Verdict::Wrong(expected.to_string()),
+        None => Verdict::Unknown,
+    })
+}
@@ -0,0 +1,127 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use rayon::prelude::*;
+
+// https://github.com/mfornet/advent-of-code-2022/blob/6aef157f13002ff7bab6390e853c420dfcd0dab5/day19b/src/beam_search.rs
+pub trait Node: Sized + Send {
+    // identifies equal states for deduplication, `()` if unused
+    type Key: Hash + Eq + Send;
+
+    fn children(&self) -> Vec<Self>;
+
+    // how promising the node is, only the best ones of a level stay in the beam
+    fn score(&self) -> usize;
+
+    // the actual value of the node, the search looks for the highest one
+    fn real_score(&self) -> usize;
+
+    // nodes with the same key are the same state, only the first one is kept
+    fn key(&self) -> Option<Self::Key> {
+        None
+    }
+
+    // the highest `real_score` any descendant could reach; nodes which cannot beat the best so far are pruned
+    fn upper_bound(&self) -> Option<usize> {
+        None
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Stats {
+    // nodes whose children have been computed
+    pub expanded: usize,
+    pub generated: usize,
+    pub duplicates: usize,
+    pub pruned: usize,
+    // number of levels until the beam ran empty
+    pub depth: usize,
+}
+
+pub struct Outcome<N> {
+    // the node with the highest real score, the source if none beats it
+    pub best: N,
+    pub stats: Stats,
+}
+
+impl<N: Node> Outcome<N> {
+    pub fn score(&self) -> usize {
+        self.best.real_score()
+    }
+}
+
+pub struct BeamSearch {
+    width: usize,
+    parallel: bool,
+}
+
+impl BeamSearch {
+    pub fn create(width: usize) -> BeamSearch {
+        BeamSearch { width, parallel: false }
+    }
+
+    // expands the nodes of a level with rayon
+    pub fn parallel(mut self, parallel: bool) -> BeamSearch {
+        self.parallel = parallel;
+        self
+    }
+
+    pub fn run<N: Node + Clone>(&self, source: N) -> Outcome<N> {
+        let mut stats = Stats::default();
+        let mut best = source.clone();
+        let mut beam = vec![source];
+
+        while !beam.is_empty() {
+            stats.expanded += beam.len();
+            let children: Vec<N> = if self.parallel {
+                beam.into_par_iter().flat_map_iter(|n| n.children()).collect()
+            } else {
+                beam.into_iter().flat_map(|n| n.children()).collect()
+            };
+            stats.generated += children.len();
+            if !children.is_empty() {
+                stats.depth += 1;
+            }
+
+            let mut seen = HashSet::new();
+            let mut next_beam = vec!();
+            for node in children {
+                if let Some(key) = node.key() {
+                    if !seen.insert(key) {
+                        stats.duplicates += 1;
+                        continue;
+                    }
+                }
+                if node.real_score() > best.real_score() {
+                    best = node.clone();
+                }
+                next_beam.push(node);
+            }
+            // pruned only now, the best of this level may have shown up after a node was taken
+            let before = next_beam.len();
+            next_beam.retain(|n| n.upper_bound().is_none_or(|bound| bound > best.real_score()));
+            stats.pruned += before - next_beam.len();
+
+            let mut next_beam: Vec<(usize, N)> = if self.parallel {
+                next_beam.into_par_iter().map(|n| (n.score(), n)).collect()
+            } else {
+                next_beam.into_iter().map(|n| (n.score(), n)).collect()
+            };
+
+            // sort by $score, trunc to first $width
+            next_beam.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+            next_beam.truncate(self.width);
+
+            beam = next_beam
+                .into_iter()
+                .map(|(_, node)| node)
+                .collect();
+        }
+
+        Outcome { best, stats }
+    }
+}
+
+pub fn beam_search<N: Node + Clone>(source: N, width: usize) -> Outcome<N> {
+    BeamSearch::create(width).run(source)
+}
@@ -0,0 +1,62 @@
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{Error, Result};
+use serde::{Deserialize, Serialize};
+
+// timing statistics of repeated runs of one puzzle, all values in nanoseconds
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Stats {
+    pub year: i32,
+    pub day: i32,
+    pub part: i32,
+    pub runs: usize,
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    pub fn create(year: i32, day: i32, part: i32, samples: &[Duration]) -> Stats {
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        nanos.sort_by(|a, b| a.total_cmp(b));
+        let runs = nanos.len();
+        if runs == 0 {
+            return Stats { year, day, part, runs, min: 0.0, median: 0.0, mean: 0.0, stddev: 0.0 };
+        }
+        let min = nanos[0];
+        let median = if runs.is_multiple_of(2) {
+            (nanos[runs / 2 - 1] + nanos[runs / 2]) / 2.0
+        } else {
+            nanos[runs / 2]
+        };
+        let mean = nanos.iter().sum::<f64>() / runs as f64;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / runs as f64;
+        Stats { year, day, part, runs, min, median, mean, stddev: variance.sqrt() }
+    }
+}
+
+// a saved benchmark, used as the baseline of a later one
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Report {
+    pub results: Vec<Stats>,
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Report> {
+        let json = fs::read_to_string(path)
+            .map_err(|e| Error::msg(format!("cannot read {}: {}", path.display(), e)))?;
+        Ok(serde_json::from_str(json.as_str())?)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn find(&self, year: i32, day: i32, part: i32) -> Option<&Stats> {
+        self.results.iter().find(|s| s.year == year && s.day == day && s.part == part)
+    }
+}
@@ -0,0 +1,223 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use anyhow::{Error, Result};
+
+// where the puzzle input is read from
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    // the file of the puzzle id in `puzzles/<year>/dayNN`
+    #[default]
+    Default,
+    // this file, whatever puzzle id is requested
+    File(PathBuf),
+    Stdin,
+}
+
+// `-` stands for stdin, everything else is a file path
+impl FromStr for InputSource {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "" => Err(Error::msg("empty input path")),
+            "-" => Ok(InputSource::Stdin),
+            _ => Ok(InputSource::File(PathBuf::from(s))),
+        }
+    }
+}
+
+// which input of a day is solved, the example of the puzzle text or the real one
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Variant {
+    Example,
+    #[default]
+    Real,
+}
+
+impl Variant {
+    pub fn puzzle(&self) -> &'static str {
+        match self {
+            Variant::Example => "puzzle0",
+            Variant::Real => "puzzle1",
+        }
+    }
+}
+
+pub struct PuzzleScope {
+    year: i32,
+    day: i32,
+    part: i32,
+    source: InputSource,
+    variant: Variant,
+    // parameters differing between the variants, like the row to look at
+    params: HashMap<String, String>,
+    // id of the last puzzle file read, used to look up its expected answer
+    loaded: RefCell<Option<String>>,
+    // where simulations dump their frames, and every how many steps
+    frames: Option<PathBuf>,
+    frame_step: usize,
+}
+
+impl Clone for PuzzleScope {
+    fn clone(&self) -> Self {
+        PuzzleScope {
+            year: self.year,
+            day: self.day,
+            part: self.part,
+            source: self.source.clone(),
+            variant: self.variant,
+            params: self.params.clone(),
+            loaded: self.loaded.clone(),
+            frames: self.frames.clone(),
+            frame_step: self.frame_step,
+        }
+    }
+}
+
+impl PuzzleScope {
+    pub fn create(year: i32, day: i32, part: i32) -> PuzzleScope {
+        PuzzleScope {
+            year,
+            day,
+            part,
+            source: InputSource::Default,
+            variant: Variant::Real,
+            params: HashMap::new(),
+            loaded: RefCell::new(None),
+            frames: None,
+            frame_step: 1,
+        }
+    }
+
+    pub fn with_source(mut self, source: InputSource) -> PuzzleScope {
+        self.source = source;
+        self
+    }
+
+    pub fn with_variant(mut self, variant: Variant) -> PuzzleScope {
+        self.variant = variant;
+        self
+    }
+
+    pub fn with_params(mut self, params: HashMap<String, String>) -> PuzzleScope {
+        self.params = params;
+        self
+    }
+
+    pub fn with_frames(mut self, dir: Option<PathBuf>, step: usize) -> PuzzleScope {
+        self.frames = dir;
+        self.frame_step = step;
+        self
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+    pub fn day(&self) -> i32 {
+        self.day
+    }
+    pub fn part(&self) -> i32 {
+        self.part
+    }
+    pub fn source(&self) -> &InputSource {
+        &self.source
+    }
+    pub fn variant(&self) -> Variant {
+        self.variant
+    }
+    // the id of the puzzle file of the variant
+    pub fn puzzle(&self) -> &'static str {
+        self.variant.puzzle()
+    }
+    pub fn param<T: FromStr>(&self, name: &str) -> Result<T> {
+        let value = self.params.get(name)
+            .ok_or_else(|| Error::msg(format!("missing parameter '{}' for {}", name, self.puzzle())))?;
+        value.parse()
+            .map_err(|_| Error::msg(format!("invalid parameter '{}' = '{}'", name, value)))
+    }
+    pub fn frames(&self) -> Option<&PathBuf> {
+        self.frames.as_ref()
+    }
+    pub fn frame_step(&self) -> usize {
+        self.frame_step
+    }
+    pub fn loaded(&self) -> Option<String> {
+        self.loaded.borrow().clone()
+    }
+    pub fn mark_loaded(&self, id: &str) {
+        self.loaded.replace(Some(id.to_string()));
+    }
+}
+
+pub fn parse_int(s: &str) -> i32 {
+    let x: i32 = s.parse().unwrap();
+    x
+}
+
+// the answer of a puzzle, most are numbers but some are words (or even rendered screens)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i64)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(i32, i64, isize, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+pub type Solution = fn(&PuzzleScope) -> Result<Answer>;
+
+pub fn measure_time(scope: &PuzzleScope, f: Solution) -> (Result<Answer>, Duration) {
+    let now = Instant::now();
+    let result = f(scope);
+    (result, now.elapsed())
+}
+
+// renders a duration with a unit fitting its magnitude, e.g. `850ns`, `12.3µs` or `1.25s`
+pub fn format_duration(nanos: f64) -> String {
+    if nanos < 1_000.0 {
+        format!("{:.0}ns", nanos)
+    } else if nanos < 1_000_000.0 {
+        format!("{:.1}µs", nanos / 1_000.0)
+    } else if nanos < 1_000_000_000.0 {
+        format!("{:.2}ms", nanos / 1_000_000.0)
+    } else {
+        format!("{:.2}s", nanos / 1_000_000_000.0)
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// A sequence of states which repeats every `length` steps, beginning with step `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    // the first step with the same state as step `n`
+    pub fn equivalent(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+// Brent's algorithm, keeps only two states at a time. The states have to repeat at some
+// point, otherwise this never returns.
+pub fn brent<S, F>(initial: S, mut step: F) -> Cycle
+    where S: Clone + Eq, F: FnMut(&S) -> S {
+    // the hare searches the length in growing powers of two
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // with the hare `length` steps ahead, both meet at the start of the cycle
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+// Floyd's tortoise and hare, same as `brent` but with more steps.
+pub fn floyd<S, F>(initial: S, mut step: F) -> Cycle
+    where S: Clone + Eq, F: FnMut(&S) -> S {
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let next = step(&hare);
+        hare = step(&next);
+    }
+
+    let mut start = 0;
+    let mut tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+    Cycle { start, length }
+}
+
+// For simulations which are too big to compare or to clone: a state is identified by its key,
+// the first key seen twice ends the cycle. None if no key repeats within `limit` steps.
+pub fn find_by_key<S, K, F, G>(state: &mut S, limit: usize, step: F, key: G) -> Option<Cycle>
+    where K: Hash + Eq, F: FnMut(&mut S), G: FnMut(&S) -> K {
+    detect(state, limit, step, key, |_| {})
+}
+
+// The value after `n` steps, like a height which grows by the same amount every cycle.
+// Simulates only until the first key repeats, or all `n` steps if none does.
+pub fn extrapolate<S, K, F, G, V>(mut state: S, n: usize, step: F, key: G, mut value: V) -> i64
+    where K: Hash + Eq, F: FnMut(&mut S), G: FnMut(&S) -> K, V: FnMut(&S) -> i64 {
+    let mut values = vec!();
+    match detect(&mut state, n, step, key, |s| values.push(value(s))) {
+        Some(cycle) => {
+            let per_cycle = values[cycle.start + cycle.length] - values[cycle.start];
+            let cycles = ((n - cycle.start) / cycle.length) as i64;
+            values[cycle.equivalent(n)] + cycles * per_cycle
+        }
+        None => values[n],
+    }
+}
+
+// `visit` sees every state, from the initial one to the repeated (or last) one
+fn detect<S, K, F, G, H>(state: &mut S, limit: usize, mut step: F, mut key: G, mut visit: H) -> Option<Cycle>
+    where K: Hash + Eq, F: FnMut(&mut S), G: FnMut(&S) -> K, H: FnMut(&S) {
+    let mut seen: HashMap<K, usize> = HashMap::new();
+    for i in 0..=limit {
+        visit(state);
+        let k = key(state);
+        if let Some(&start) = seen.get(&k) {
+            return Some(Cycle { start, length: i - start });
+        }
+        if i == limit {
+            break;
+        }
+        seen.insert(k, i);
+        step(state);
+    }
+    None
+}
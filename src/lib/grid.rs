@@ -0,0 +1,310 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Display, Formatter};
+
+use anyhow::{bail, Result};
+
+use crate::lib::point::Point2D;
+
+// A dense grid, stored row-major (index = y * width + x).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid2D<T> {
+    height: i32,
+    width: i32,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid2D<T> {
+    pub fn create(height: i32, width: i32, value: T) -> Grid2D<T> {
+        Grid2D {
+            height,
+            width,
+            cells: vec![value; (height * width) as usize],
+        }
+    }
+}
+
+impl Grid2D<char> {
+    pub fn parse_chars(text: &str) -> Result<Grid2D<char>> {
+        Grid2D::parse(text, Some)
+    }
+}
+
+impl<T> Grid2D<T> {
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid2D<T>> {
+        let height = rows.len() as i32;
+        let width = rows.first().map(|r| r.len()).unwrap_or(0);
+        let mut cells = Vec::with_capacity(height as usize * width);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                bail!("row {}: expected {} cells, got {}", y + 1, width, row.len());
+            }
+            cells.extend(row);
+        }
+        Ok(Grid2D {
+            height,
+            width: width as i32,
+            cells,
+        })
+    }
+
+    // Each non-empty line is a row, each char a cell; `f` returns None for unknown chars.
+    pub fn parse<F>(text: &str, mut f: F) -> Result<Grid2D<T>>
+        where F: FnMut(char) -> Option<T> {
+        let mut rows = Vec::new();
+        for (y, line) in text.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+            let mut row = Vec::with_capacity(line.len());
+            for (x, c) in line.chars().enumerate() {
+                match f(c) {
+                    Some(v) => row.push(v),
+                    None => bail!("{}:{}: unexpected '{}'", y + 1, x + 1, c),
+                }
+            }
+            if let Some(width) = rows.first().map(Vec::len).filter(|&w| w != row.len()) {
+                bail!("{}:{}: expected {} cells, got {}", y + 1, width.min(row.len()) + 1, width, row.len());
+            }
+            rows.push(row);
+        }
+        Grid2D::from_rows(rows)
+    }
+
+    pub fn size(&self) -> i32 {
+        self.height * self.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        (0..self.width).contains(&x) && (0..self.height).contains(&y)
+    }
+
+    fn index(&self, x: i32, y: i32) -> Option<usize> {
+        if self.contains(x, y) {
+            Some((y * self.width + x) as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, x: i32, y: i32) -> Option<&T> {
+        self.index(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, x: i32, y: i32) -> Option<&mut T> {
+        self.index(x, y).map(|i| &mut self.cells[i])
+    }
+
+    // Returns the replaced value, or None if (x, y) is outside the grid.
+    pub fn set(&mut self, x: i32, y: i32, value: T) -> Option<T> {
+        self.get_mut(x, y).map(|cell| std::mem::replace(cell, value))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item=&[T]> + '_ {
+        self.cells.chunks(self.width.max(1) as usize)
+    }
+
+    pub fn row(&self, y: i32) -> impl Iterator<Item=&T> + '_ {
+        (0..self.width).filter_map(move |x| self.get(x, y))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item=impl Iterator<Item=&T> + '_> + '_ {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    pub fn column(&self, x: i32) -> impl Iterator<Item=&T> + '_ {
+        (0..self.height).filter_map(move |y| self.get(x, y))
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item=(Point2D, &T)> + '_ {
+        let w = self.width.max(1);
+        self.cells.iter()
+            .enumerate()
+            .map(move |(i, v)| (Point2D::create(i as i32 % w, i as i32 / w), v))
+    }
+
+    pub fn cells_mut(&mut self) -> impl Iterator<Item=(Point2D, &mut T)> + '_ {
+        let w = self.width.max(1);
+        self.cells.iter_mut()
+            .enumerate()
+            .map(move |(i, v)| (Point2D::create(i as i32 % w, i as i32 / w), v))
+    }
+
+    pub fn position<P>(&self, mut predicate: P) -> Option<Point2D>
+        where P: FnMut(&T) -> bool {
+        self.cells().find(|(_, v)| predicate(v)).map(|(p, _)| p)
+    }
+
+    // Orthogonal neighbours inside the grid.
+    pub fn neighbours(&self, x: i32, y: i32) -> impl Iterator<Item=(Point2D, &T)> + '_ {
+        self.lookup_all(Point2D::create(x, y).adjacents4())
+    }
+
+    // Orthogonal and diagonal neighbours inside the grid.
+    pub fn neighbours8(&self, x: i32, y: i32) -> impl Iterator<Item=(Point2D, &T)> + '_ {
+        self.lookup_all(Point2D::create(x, y).adjacents())
+    }
+
+    // Cells starting next to (x, y), stepping by (dx, dy) until leaving the grid.
+    pub fn ray(&self, x: i32, y: i32, (dx, dy): (i32, i32)) -> impl Iterator<Item=(Point2D, &T)> + '_ {
+        (1..)
+            .map(move |i| Point2D::create(x + dx * i, y + dy * i))
+            .map_while(move |p| self.get(p.x(), p.y()).map(|v| (p, v)))
+    }
+
+    fn lookup_all(&self, points: Vec<Point2D>) -> impl Iterator<Item=(Point2D, &T)> + '_ {
+        points.into_iter()
+            .filter_map(move |p| self.get(p.x(), p.y()).map(|v| (p, v)))
+    }
+}
+
+impl<T: Display> Display for Grid2D<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for v in row {
+                write!(f, "{}", v)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+// An unbounded grid of occupied cells. The bounding box follows inserts and removes by
+// counting the cells per column and row.
+#[derive(Clone, Debug, Default)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2D, T>,
+    columns: BTreeMap<i32, usize>,
+    rows: BTreeMap<i32, usize>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+            columns: BTreeMap::new(),
+            rows: BTreeMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, p: &Point2D) -> bool {
+        self.cells.contains_key(p)
+    }
+
+    pub fn get(&self, p: &Point2D) -> Option<&T> {
+        self.cells.get(p)
+    }
+
+    pub fn get_mut(&mut self, p: &Point2D) -> Option<&mut T> {
+        self.cells.get_mut(p)
+    }
+
+    pub fn insert(&mut self, p: Point2D, value: T) -> Option<T> {
+        let previous = self.cells.insert(p, value);
+        if previous.is_none() {
+            *self.columns.entry(p.x()).or_insert(0) += 1;
+            *self.rows.entry(p.y()).or_insert(0) += 1;
+        }
+        previous
+    }
+
+    pub fn remove(&mut self, p: &Point2D) -> Option<T> {
+        let removed = self.cells.remove(p);
+        if removed.is_some() {
+            SparseGrid::<T>::release(&mut self.columns, p.x());
+            SparseGrid::<T>::release(&mut self.rows, p.y());
+        }
+        removed
+    }
+
+    fn release(counts: &mut BTreeMap<i32, usize>, key: i32) {
+        if let Some(count) = counts.get_mut(&key) {
+            *count -= 1;
+            if *count == 0 {
+                counts.remove(&key);
+            }
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=(&Point2D, &T)> + '_ {
+        self.cells.iter()
+    }
+
+    pub fn points(&self) -> impl Iterator<Item=&Point2D> + '_ {
+        self.cells.keys()
+    }
+
+    // Smallest and largest corner (inclusive) of all occupied cells.
+    pub fn bounds(&self) -> Option<(Point2D, Point2D)> {
+        let (min_x, max_x) = (self.columns.keys().next()?, self.columns.keys().next_back()?);
+        let (min_y, max_y) = (self.rows.keys().next()?, self.rows.keys().next_back()?);
+        Some((Point2D::create(*min_x, *min_y), Point2D::create(*max_x, *max_y)))
+    }
+
+    pub fn area(&self) -> usize {
+        match self.bounds() {
+            Some((min, max)) => ((max.x() - min.x() + 1) as usize) * ((max.y() - min.y() + 1) as usize),
+            None => 0,
+        }
+    }
+
+    // Unoccupied cells inside the bounding box.
+    pub fn count_empty(&self) -> usize {
+        self.area() - self.len()
+    }
+
+    // Cells inside the region (inclusive corners) that match the predicate; None is an empty cell.
+    pub fn count_region<P>(&self, min: Point2D, max: Point2D, mut predicate: P) -> usize
+        where P: FnMut(Option<&T>) -> bool {
+        (min.y()..=max.y())
+            .flat_map(|y| (min.x()..=max.x()).map(move |x| Point2D::create(x, y)))
+            .filter(|p| predicate(self.get(p)))
+            .count()
+    }
+
+    pub fn render<F>(&self, format: F) -> String
+        where F: FnMut(Option<&T>) -> char {
+        match self.bounds() {
+            Some((min, max)) => self.render_region(min, max, format),
+            None => String::new(),
+        }
+    }
+
+    pub fn render_region<F>(&self, min: Point2D, max: Point2D, mut format: F) -> String
+        where F: FnMut(Option<&T>) -> char {
+        let mut result = String::new();
+        for y in min.y()..=max.y() {
+            for x in min.x()..=max.x() {
+                result.push(format(self.get(&Point2D::create(x, y))));
+            }
+            result.push('\n');
+        }
+        result
+    }
+}
+
+impl<T> FromIterator<(Point2D, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item=(Point2D, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (p, v) in iter {
+            grid.insert(p, v);
+        }
+        grid
+    }
+}
@@ -0,0 +1,212 @@
+use std::fs;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+use crate::lib::common::PuzzleScope;
+use crate::lib::grid::{Grid2D, SparseGrid};
+use crate::lib::point::Point2D;
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+pub const GRAY: Rgb = [128, 128, 128];
+pub const RED: Rgb = [220, 50, 47];
+pub const GREEN: Rgb = [133, 153, 0];
+pub const BLUE: Rgb = [38, 139, 210];
+pub const YELLOW: Rgb = [181, 137, 0];
+pub const SAND: Rgb = [194, 178, 128];
+
+// An RGB image, row-major like `Grid2D`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn create(width: usize, height: usize, background: Rgb) -> Image {
+        Image {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    // One pixel per cell of the region (inclusive corners).
+    pub fn render<F>(min: Point2D, max: Point2D, mut palette: F) -> Image
+        where F: FnMut(Point2D) -> Rgb {
+        let width = (max.x() - min.x() + 1).max(0) as usize;
+        let height = (max.y() - min.y() + 1).max(0) as usize;
+        let mut pixels = Vec::with_capacity(width * height);
+        for y in min.y()..=max.y() {
+            for x in min.x()..=max.x() {
+                pixels.push(palette(Point2D::create(x, y)));
+            }
+        }
+        Image { width, height, pixels }
+    }
+
+    pub fn from_grid<T, F>(grid: &Grid2D<T>, mut palette: F) -> Image
+        where F: FnMut(&T) -> Rgb {
+        let max = Point2D::create(grid.width() - 1, grid.height() - 1);
+        Image::render(Point2D::create(0, 0), max, |p| palette(grid.get(p.x(), p.y()).unwrap()))
+    }
+
+    // Covers the bounding box of the grid, None is an empty cell.
+    pub fn from_sparse<T, F>(grid: &SparseGrid<T>, mut palette: F) -> Image
+        where F: FnMut(Option<&T>) -> Rgb {
+        match grid.bounds() {
+            Some((min, max)) => Image::render(min, max, |p| palette(grid.get(&p))),
+            None => Image::create(0, 0, BLACK),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<Rgb> {
+        if x < self.width && y < self.height {
+            Some(self.pixels[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, color: Rgb) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = color;
+        }
+    }
+
+    // Each pixel becomes a `factor` x `factor` square, puzzle grids are tiny otherwise.
+    pub fn scale(&self, factor: usize) -> Image {
+        let width = self.width * factor;
+        let height = self.height * factor;
+        let mut pixels = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                pixels.push(self.pixels[(y / factor) * self.width + x / factor]);
+            }
+        }
+        Image { width, height, pixels }
+    }
+
+    pub fn write_ppm<W: Write>(&self, out: &mut W) -> Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.pixels.concat())?;
+        Ok(())
+    }
+
+    pub fn write_png<W: Write>(&self, out: W) -> Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels.concat())?;
+        Ok(())
+    }
+
+    // The format follows the extension of the path, `.ppm` or `.png`.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let file = File::create(path)
+            .with_context(|| format!("cannot create image {}", path.display()))?;
+        let mut out = BufWriter::new(file);
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("ppm") => self.write_ppm(&mut out),
+            Some("png") => self.write_png(&mut out),
+            _ => bail!("unknown image format of {}", path.display()),
+        }
+        .with_context(|| format!("cannot write image {}", path.display()))
+    }
+}
+
+// Dumps one image per simulation step as `<dir>/<name>-00001.png` and so on. Without a
+// frame directory on the scope it records nothing and the images are never rendered.
+pub struct FrameRecorder {
+    dir: Option<PathBuf>,
+    name: String,
+    every: usize,
+    scale: usize,
+    step: usize,
+    frames: usize,
+}
+
+impl FrameRecorder {
+    pub fn create(dir: Option<PathBuf>, name: &str) -> Result<FrameRecorder> {
+        if let Some(dir) = &dir {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("cannot create frame directory {}", dir.display()))?;
+        }
+        Ok(FrameRecorder {
+            dir,
+            name: name.to_string(),
+            every: 1,
+            scale: 1,
+            step: 0,
+            frames: 0,
+        })
+    }
+
+    pub fn for_scope(scope: &PuzzleScope, name: &str) -> Result<FrameRecorder> {
+        let name = format!("day{:02}-part{}-{}", scope.day(), scope.part(), name);
+        let recorder = FrameRecorder::create(scope.frames().cloned(), &name)?;
+        Ok(recorder.every(scope.frame_step()))
+    }
+
+    // Only every n-th step becomes a frame.
+    pub fn every(mut self, n: usize) -> FrameRecorder {
+        self.every = n.max(1);
+        self
+    }
+
+    pub fn scale(mut self, factor: usize) -> FrameRecorder {
+        self.scale = factor.max(1);
+        self
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.dir.is_some()
+    }
+
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    pub fn record<F>(&mut self, render: F) -> Result<()>
+        where F: FnOnce() -> Image {
+        let step = self.step;
+        self.step += 1;
+        if !self.enabled() || !step.is_multiple_of(self.every) {
+            return Ok(());
+        }
+        self.write(render())
+    }
+
+    // The last state, regardless of the step.
+    pub fn finish<F>(&mut self, render: F) -> Result<()>
+        where F: FnOnce() -> Image {
+        if !self.enabled() {
+            return Ok(());
+        }
+        self.write(render())
+    }
+
+    fn write(&mut self, image: Image) -> Result<()> {
+        if let Some(dir) = &self.dir {
+            self.frames += 1;
+            let path = dir.join(format!("{}-{:05}.png", self.name, self.frames));
+            image.scale(self.scale).save(&path)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,185 @@
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+// Integers which can be enumerated; `succ`/`pred` are never called on the extremes.
+pub trait Discrete: Copy + Ord + Debug {
+    fn succ(self) -> Self;
+    fn pred(self) -> Self;
+
+    // the number of values of `lo..=hi`, does not fit the type itself for `MIN..=MAX`
+    fn count(lo: Self, hi: Self) -> u128;
+}
+
+macro_rules! impl_discrete {
+    ($($t:ty),*) => {
+        $(
+            impl Discrete for $t {
+                fn succ(self) -> Self {
+                    self + 1
+                }
+
+                fn pred(self) -> Self {
+                    self - 1
+                }
+
+                fn count(lo: Self, hi: Self) -> u128 {
+                    (hi as i128 - lo as i128 + 1) as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_discrete!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+// Sorted, disjoint and non-adjacent inclusive ranges; `1..=2` and `3..=4` become `1..=4`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T: Discrete> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { ranges: vec!() }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    // the number of values in the set
+    pub fn len(&self) -> u128 {
+        self.ranges.iter().map(|&(lo, hi)| T::count(lo, hi)).sum()
+    }
+
+    pub fn ranges(&self) -> impl Iterator<Item=RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(lo, hi)| lo..=hi)
+    }
+
+    // the ranges between two ranges of the set, before the first and after the last are none
+    pub fn gaps(&self) -> impl Iterator<Item=RangeInclusive<T>> + '_ {
+        self.ranges.windows(2).map(|w| w[0].1.succ()..=w[1].0.pred())
+    }
+
+    // from the lowest to the highest value
+    pub fn span(&self) -> Option<RangeInclusive<T>> {
+        match (self.ranges.first(), self.ranges.last()) {
+            (Some(&(lo, _)), Some(&(_, hi))) => Some(lo..=hi),
+            _ => None,
+        }
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let idx = self.ranges.partition_point(|&(_, hi)| hi < *value);
+        self.ranges.get(idx).is_some_and(|&(lo, _)| lo <= *value)
+    }
+
+    // every value of the range is in the set, an empty range always is
+    pub fn contains_range(&self, range: &RangeInclusive<T>) -> bool {
+        if range.is_empty() {
+            return true;
+        }
+        let idx = self.ranges.partition_point(|&(_, hi)| hi < *range.start());
+        self.ranges.get(idx).is_some_and(|&(lo, hi)| lo <= *range.start() && *range.end() <= hi)
+    }
+
+    // any value of the range is in the set
+    pub fn overlaps(&self, range: &RangeInclusive<T>) -> bool {
+        if range.is_empty() {
+            return false;
+        }
+        let idx = self.ranges.partition_point(|&(_, hi)| hi < *range.start());
+        self.ranges.get(idx).is_some_and(|&(lo, _)| lo <= *range.end())
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let (mut lo, mut hi) = range.into_inner();
+        // ranges touching the new one are merged as well; `x < y` guarantees `x.succ()` exists
+        let from = self.ranges.partition_point(|&(_, end)| end < lo && end.succ() < lo);
+        let to = self.ranges.partition_point(|&(start, _)| start <= hi || start.pred() <= hi);
+        if from < to {
+            lo = lo.min(self.ranges[from].0);
+            hi = hi.max(self.ranges[to - 1].1);
+        }
+        self.ranges.splice(from..to, [(lo, hi)]);
+    }
+
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let (lo, hi) = range.into_inner();
+        let from = self.ranges.partition_point(|&(_, end)| end < lo);
+        let to = self.ranges.partition_point(|&(start, _)| start <= hi);
+        if from >= to {
+            return;
+        }
+        let mut rest = vec!();
+        let (first_lo, _) = self.ranges[from];
+        if first_lo < lo {
+            rest.push((first_lo, lo.pred()));
+        }
+        let (_, last_hi) = self.ranges[to - 1];
+        if hi < last_hi {
+            rest.push((hi.succ(), last_hi));
+        }
+        self.ranges.splice(from..to, rest);
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = self.clone();
+        for range in other.ranges() {
+            result.insert(range);
+        }
+        result
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges = vec!();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a_lo, a_hi) = self.ranges[i];
+            let (b_lo, b_hi) = other.ranges[j];
+            let (lo, hi) = (a_lo.max(b_lo), a_hi.min(b_hi));
+            if lo <= hi {
+                ranges.push((lo, hi));
+            }
+            if a_hi < b_hi {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    // everything within `bounds` which is not in the set
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> IntervalSet<T> {
+        let mut result = IntervalSet::from(bounds);
+        for range in self.ranges() {
+            result.remove(range);
+        }
+        result
+    }
+}
+
+impl<T: Discrete> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let mut result = IntervalSet::new();
+        result.insert(range);
+        result
+    }
+}
+
+impl<T: Discrete> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item=RangeInclusive<T>>>(iter: I) -> Self {
+        let mut result = IntervalSet::new();
+        for range in iter {
+            result.insert(range);
+        }
+        result
+    }
+}
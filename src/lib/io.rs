@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fs;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use anyhow::{anyhow, Context, Error, Result};
+
+use crate::lib::common::{InputSource, PuzzleScope};
+use crate::lib::grid::Grid2D;
+
+// stdin can be consumed only once, but every part (and bench run) may ask for it
+static STDIN: OnceLock<Result<String, String>> = OnceLock::new();
+
+pub fn day_path(scope: &PuzzleScope, name: &str) -> PathBuf {
+    PathBuf::from(format!("puzzles/{}/day{:02}/{}", scope.year(), scope.day(), name))
+}
+
+// parameters of the scope's variant, stored in `puzzles/<year>/dayNN/params`
+// every line is `<puzzle> <name> <value>` like `puzzle0 row 10`, `#` starts a comment
+pub fn read_params(scope: &PuzzleScope) -> Result<HashMap<String, String>> {
+    let path = day_path(scope, "params");
+    let mut params = HashMap::new();
+    if !path.exists() {
+        return Ok(params);
+    }
+    let content = fs::read_to_string(&path)
+        .with_context(|| format!("cannot read {}", path.display()))?;
+    for (idx, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let split: Vec<&str> = line.splitn(3, ' ').collect();
+        if split.len() != 3 {
+            return Err(Error::msg(format!("{}:{}: expected '<puzzle> <name> <value>'", path.display(), idx + 1)));
+        }
+        if split[0] == scope.puzzle() {
+            params.insert(split[1].to_string(), split[2].trim().to_string());
+        }
+    }
+    Ok(params)
+}
+
+// the file of a puzzle id, stdin has none
+pub fn puzzle_path(scope: &PuzzleScope, id: &str) -> Option<PathBuf> {
+    match scope.source() {
+        InputSource::Default => Some(day_path(scope, format!("{}.txt", id).as_str())),
+        InputSource::File(path) => Some(path.clone()),
+        InputSource::Stdin => None,
+    }
+}
+
+// the puzzle input as named in messages
+pub fn puzzle_name(scope: &PuzzleScope, id: &str) -> String {
+    match puzzle_path(scope, id) {
+        Some(path) => path.display().to_string(),
+        None => "<stdin>".to_string(),
+    }
+}
+
+fn read_stdin() -> Result<String> {
+    STDIN
+        .get_or_init(|| {
+            let mut content = String::new();
+            std::io::stdin().read_to_string(&mut content)
+                .map(|_| content)
+                .map_err(|e| e.to_string())
+        })
+        .clone()
+        .map_err(|e| Error::msg(format!("cannot read puzzle from stdin: {}", e)))
+}
+
+// every reader marks the puzzle as loaded, its expected answer is looked up afterwards
+pub fn read_puzzle_as_string(scope: &PuzzleScope, id: &str) -> Result<String> {
+    scope.mark_loaded(id);
+    match puzzle_path(scope, id) {
+        Some(path) => fs::read_to_string(&path)
+            .with_context(|| format!("cannot read puzzle file {}", path.display())),
+        None => read_stdin(),
+    }
+}
+
+#[allow(dead_code)]
+pub fn read_puzzle_as_list(scope: &PuzzleScope, id: &str) -> Result<Vec<String>> {
+    let mut res: Vec<String> = Vec::new();
+    for value in read_puzzle_as_string(scope, id)?
+        .split("\n") {
+        res.push(String::from(value));
+    }
+    Ok(res)
+}
+
+// a trailing newline at the end of the file is fine, any other empty line is not a number
+#[allow(dead_code)]
+pub fn read_puzzle_as_ints(scope: &PuzzleScope, id: &str) -> Result<Vec<i32>> {
+    let content = read_puzzle_as_string(scope, id)?;
+    let mut res: Vec<i32> = Vec::new();
+    for (idx, v) in content
+        .strip_suffix("\n")
+        .unwrap_or(content.as_str())
+        .split("\n")
+        .enumerate() {
+        let type_value: i32 = v.trim_end_matches('\r').parse()
+            .with_context(|| format!("{}:{}: cannot parse '{}' as number", puzzle_name(scope, id), idx + 1, v))?;
+        res.push(type_value);
+    }
+    Ok(res)
+}
+
+#[allow(dead_code)]
+pub fn read_puzzle_first_line(scope: &PuzzleScope, id: &str) -> Result<String> {
+    scope.mark_loaded(id);
+    let path = match puzzle_path(scope, id) {
+        Some(path) => path,
+        None => {
+            let content = read_stdin()?;
+            return Ok(content.split_inclusive('\n').next().unwrap_or_default().to_string());
+        }
+    };
+    println!("{}", path.display());
+    let file = File::open(&path)
+        .with_context(|| format!("cannot read puzzle file {}", path.display()))?;
+    let mut buffer = BufReader::new(file);
+    let mut line = String::new();
+    buffer.read_line(&mut line)
+        .with_context(|| format!("cannot read first line of {}", path.display()))?;
+    Ok(line)
+}
+
+// the lines with their 1-based number, without line endings
+fn numbered_lines(content: &str) -> impl Iterator<Item=(usize, &str)> {
+    content.lines()
+        .map(|line| line.trim_end_matches('\r'))
+        .enumerate()
+        .map(|(idx, line)| (idx + 1, line))
+}
+
+// every non-empty line parsed as `T`
+pub fn read_puzzle_as<T>(scope: &PuzzleScope, id: &str) -> Result<Vec<T>>
+    where T: FromStr, T::Err: Display {
+    let content = read_puzzle_as_string(scope, id)?;
+    let mut res = Vec::new();
+    for (line_no, line) in numbered_lines(&content) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let col = line.len() - line.trim_start().len() + 1;
+        let value = line.trim().parse::<T>()
+            .map_err(|e| anyhow!("{}:{}:{}: cannot parse '{}': {}", puzzle_name(scope, id), line_no, col, line, e))?;
+        res.push(value);
+    }
+    Ok(res)
+}
+
+// groups of lines separated by one or more empty lines
+pub fn read_puzzle_as_blocks(scope: &PuzzleScope, id: &str) -> Result<Vec<Vec<String>>> {
+    let content = read_puzzle_as_string(scope, id)?;
+    Ok(split_blocks(&content)
+        .into_iter()
+        .map(|(_, lines)| lines.into_iter().map(String::from).collect())
+        .collect())
+}
+
+// every block parsed as `T` from its lines joined by `\n`
+pub fn read_puzzle_as_blocks_of<T>(scope: &PuzzleScope, id: &str) -> Result<Vec<T>>
+    where T: FromStr, T::Err: Display {
+    let content = read_puzzle_as_string(scope, id)?;
+    let mut res = Vec::new();
+    for (line_no, lines) in split_blocks(&content) {
+        let value = lines.join("\n").parse::<T>()
+            .map_err(|e| anyhow!("{}:{}:1: cannot parse block: {}", puzzle_name(scope, id), line_no, e))?;
+        res.push(value);
+    }
+    Ok(res)
+}
+
+// the blocks with the number of their first line
+fn split_blocks(content: &str) -> Vec<(usize, Vec<&str>)> {
+    let mut blocks: Vec<(usize, Vec<&str>)> = Vec::new();
+    let mut current: Option<(usize, Vec<&str>)> = None;
+    for (line_no, line) in numbered_lines(content) {
+        if line.trim().is_empty() {
+            blocks.extend(current.take());
+        } else {
+            current.get_or_insert_with(|| (line_no, Vec::new())).1.push(line);
+        }
+    }
+    blocks.extend(current);
+    blocks
+}
+
+// All signed integers of a line like `Sensor at x=2, y=-18`, anything else is skipped.
+// A `-` only counts as sign in front of a digit and not right after one, `5-7` is a range.
+pub fn ints_in<T>(line: &str) -> Result<Vec<T>>
+    where T: FromStr, T::Err: Display {
+    let bytes = line.as_bytes();
+    let mut res = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let negative = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && !(i > 0 && bytes[i - 1].is_ascii_digit());
+        if !negative && !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        let v = &line[start..i];
+        res.push(v.parse::<T>().map_err(|e| anyhow!("{}: cannot parse '{}': {}", start + 1, v, e))?);
+    }
+    Ok(res)
+}
+
+// the integers of every line, empty lines included (as empty list)
+pub fn read_puzzle_as_int_lines<T>(scope: &PuzzleScope, id: &str) -> Result<Vec<Vec<T>>>
+    where T: FromStr, T::Err: Display {
+    let content = read_puzzle_as_string(scope, id)?;
+    numbered_lines(&content)
+        .map(|(line_no, line)| ints_in(line)
+            .map_err(|e| anyhow!("{}:{}:{}", puzzle_name(scope, id), line_no, e)))
+        .collect()
+}
+
+// `f` returns None for unknown chars, empty lines are skipped
+pub fn read_puzzle_as_grid<T, F>(scope: &PuzzleScope, id: &str, f: F) -> Result<Grid2D<T>>
+    where F: FnMut(char) -> Option<T> {
+    let content = read_puzzle_as_string(scope, id)?;
+    Grid2D::parse(&content, f)
+        .map_err(|e| anyhow!("{}:{}", puzzle_name(scope, id), e))
+}
+
+pub fn read_puzzle_as_char_grid(scope: &PuzzleScope, id: &str) -> Result<Grid2D<char>> {
+    read_puzzle_as_grid(scope, id, Some)
+}
@@ -0,0 +1,25 @@
+pub mod answers;
+#[allow(dead_code)]
+pub mod beam_search;
+pub mod bench;
+pub mod common;
+#[allow(dead_code)]
+pub mod cycle;
+#[allow(dead_code)]
+pub mod io;
+#[allow(dead_code)]
+pub mod grid;
+#[allow(dead_code)]
+pub mod image;
+#[allow(dead_code)]
+pub mod interval;
+#[allow(dead_code)]
+pub mod ocr;
+#[allow(dead_code)]
+pub mod point;
+#[allow(dead_code)]
+pub mod search;
+pub mod style;
+
+#[cfg(test)]
+mod tests;
\ No newline at end of file
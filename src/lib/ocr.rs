@@ -0,0 +1,108 @@
+use anyhow::{bail, Result};
+
+use crate::lib::grid::Grid2D;
+
+// A fixed-size block letter font as drawn by the puzzles, glyph rows joined by `\n`.
+pub struct Font {
+    pub width: usize,
+    pub height: usize,
+    // empty columns between two glyphs
+    pub spacing: usize,
+    pub glyphs: &'static [(char, &'static str)],
+}
+
+// used by most puzzles (e.g. 2016/8, 2019/8, 2021/13, 2022/10), some letters never showed up
+pub const FONT_4X6: Font = Font {
+    width: 4,
+    height: 6,
+    spacing: 1,
+    glyphs: &[
+        ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+        ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+        ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+        ('E', "####\n#...\n###.\n#...\n#...\n####"),
+        ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+        ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+        ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+        ('I', ".###\n..#.\n..#.\n..#.\n..#.\n.###"),
+        ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+        ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+        ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+        ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+        ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+        ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+        ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+        ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+        ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+    ],
+};
+
+// used by 2018/10
+pub const FONT_6X10: Font = Font {
+    width: 6,
+    height: 10,
+    spacing: 2,
+    glyphs: &[
+        ('A', "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#"),
+        ('B', "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####."),
+        ('C', ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####."),
+        ('E', "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######"),
+        ('F', "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#....."),
+        ('G', ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#"),
+        ('H', "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#"),
+        ('J', "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###.."),
+        ('K', "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#"),
+        ('L', "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######"),
+        ('N', "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#"),
+        ('P', "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#....."),
+        ('R', "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#"),
+        ('X', "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#"),
+        ('Z', "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######"),
+    ],
+};
+
+// Lit pixels are `#`, everything else is dark.
+pub fn parse_screen(text: &str) -> Result<Grid2D<bool>> {
+    Grid2D::parse(text, |c| Some(c == '#'))
+}
+
+// Picks the font by the height of the screen.
+pub fn recognize(screen: &Grid2D<bool>) -> Result<String> {
+    match screen.height() as usize {
+        h if h == FONT_4X6.height => recognize_with(&FONT_4X6, screen),
+        h if h == FONT_6X10.height => recognize_with(&FONT_6X10, screen),
+        h => bail!("no font with a height of {} pixels", h),
+    }
+}
+
+// Reads one glyph per cell of `width + spacing` columns, pixels in the spacing are ignored.
+pub fn recognize_with(font: &Font, screen: &Grid2D<bool>) -> Result<String> {
+    if screen.height() as usize != font.height {
+        bail!("expected a screen of {} pixels height, got {}", font.height, screen.height());
+    }
+    let cell = font.width + font.spacing;
+    let count = (screen.width() as usize + font.spacing) / cell;
+    let mut result = String::new();
+    for i in 0..count {
+        let glyph = extract_glyph(font, screen, i * cell);
+        match font.glyphs.iter().find(|(_, g)| *g == glyph) {
+            Some((c, _)) => result.push(*c),
+            None => bail!("unknown letter #{} at column {}:\n{}", i + 1, i * cell, glyph),
+        }
+    }
+    Ok(result)
+}
+
+fn extract_glyph(font: &Font, screen: &Grid2D<bool>, offset: usize) -> String {
+    (0..font.height)
+        .map(|y| {
+            (offset..offset + font.width)
+                .map(|x| match screen.get(x as i32, y as i32) {
+                    Some(true) => '#',
+                    _ => '.',
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
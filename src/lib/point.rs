@@ -0,0 +1,363 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use anyhow::{Error, Result};
+
+// The integer types a point can be made of.
+pub trait Coordinate: Copy + Default + Debug + Display + Eq + Ord + Hash + FromStr
++ Add<Output=Self> + Sub<Output=Self> + Mul<Output=Self> + Neg<Output=Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn distance(self, other: Self) -> usize;
+}
+
+macro_rules! impl_coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn distance(self, other: Self) -> usize {
+                    self.abs_diff(other) as usize
+                }
+            }
+        )*
+    };
+}
+
+impl_coordinate!(i8, i16, i32, i64, isize);
+
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Point2D<T = i32> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coordinate> Display for Point2D<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}/{})", self.x, self.y)
+    }
+}
+
+impl<T: Coordinate> Point2D<T> {
+    pub fn create(x: T, y: T) -> Self {
+        Point2D {
+            x,
+            y,
+        }
+    }
+
+    pub fn x(&self) -> T {
+        self.x
+    }
+    pub fn y(&self) -> T {
+        self.y
+    }
+
+    pub fn left(&self) -> Self {
+        Point2D::create(self.x() - T::ONE, self.y())
+    }
+
+    pub fn right(&self) -> Self {
+        Point2D::create(self.x() + T::ONE, self.y())
+    }
+
+    pub fn top(&self) -> Self {
+        Point2D::create(self.x(), self.y() - T::ONE)
+    }
+
+    pub fn bottom(&self) -> Self {
+        Point2D::create(self.x(), self.y() + T::ONE)
+    }
+
+    pub fn adjacents(&self) -> Vec<Self> {
+        let x = self.x();
+        let y = self.y();
+        let one = T::ONE;
+        vec![
+            Point2D::create(x - one, y - one),
+            Point2D::create(x, y - one),
+            Point2D::create(x + one, y - one),
+            Point2D::create(x - one, y),
+            Point2D::create(x + one, y),
+            Point2D::create(x - one, y + one),
+            Point2D::create(x, y + one),
+            Point2D::create(x + one, y + one),
+        ]
+    }
+
+    pub fn adjacents4(&self) -> Vec<Self> {
+        let x = self.x();
+        let y = self.y();
+        let one = T::ONE;
+        vec![
+            Point2D::create(x, y - one),
+            Point2D::create(x - one, y),
+            Point2D::create(x + one, y),
+            Point2D::create(x, y + one),
+        ]
+    }
+
+    pub fn manhatten_distance(&self, other: &Self) -> usize {
+        self.x.distance(other.x) + self.y.distance(other.y)
+    }
+
+    // moves in all eight directions count as one step
+    pub fn chebyshev_distance(&self, other: &Self) -> usize {
+        self.x.distance(other.x).max(self.y.distance(other.y))
+    }
+
+    // by 90°, the y-axis pointing down like on the screen
+    pub fn rotate_left(&self) -> Self {
+        Point2D::create(self.y, -self.x)
+    }
+
+    pub fn rotate_right(&self) -> Self {
+        Point2D::create(-self.y, self.x)
+    }
+}
+
+impl<T: Coordinate> Add for Point2D<T> {
+    type Output = Point2D<T>;
+
+    fn add(self, rhs: Self) -> Point2D<T> {
+        Point2D {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<T: Coordinate> Sub for Point2D<T> {
+    type Output = Point2D<T>;
+
+    fn sub(self, rhs: Self) -> Point2D<T> {
+        Point2D {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl<T: Coordinate> Mul<T> for Point2D<T> {
+    type Output = Point2D<T>;
+
+    fn mul(self, rhs: T) -> Point2D<T> {
+        Point2D {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl<T: Coordinate> Neg for Point2D<T> {
+    type Output = Point2D<T>;
+
+    fn neg(self) -> Point2D<T> {
+        Point2D {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<T: Coordinate> AddAssign for Point2D<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Coordinate> SubAssign for Point2D<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+// like `3,4`
+impl<T: Coordinate> FromStr for Point2D<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match parse_coordinates(s)?[..] {
+            [x, y] => Ok(Point2D::create(x, y)),
+            _ => Err(Error::msg(format!("expected 2 coordinates, got '{s}'"))),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Point3D<T = i32> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coordinate> Display for Point3D<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}/{}/{})", self.x, self.y, self.z)
+    }
+}
+
+impl<T: Coordinate> Point3D<T> {
+    pub fn create(x: T, y: T, z: T) -> Point3D<T> {
+        Point3D {
+            x,
+            y,
+            z,
+        }
+    }
+
+    pub fn x(&self) -> T {
+        self.x
+    }
+    pub fn y(&self) -> T {
+        self.y
+    }
+    pub fn z(&self) -> T {
+        self.z
+    }
+
+    // sharing a face
+    pub fn neighbours6(&self) -> Vec<Self> {
+        let (zero, one) = (T::ZERO, T::ONE);
+        vec![
+            Point3D::create(-one, zero, zero),
+            Point3D::create(one, zero, zero),
+            Point3D::create(zero, -one, zero),
+            Point3D::create(zero, one, zero),
+            Point3D::create(zero, zero, -one),
+            Point3D::create(zero, zero, one),
+        ]
+            .into_iter()
+            .map(|offset| *self + offset)
+            .collect()
+    }
+
+    // sharing a face, an edge or a corner
+    pub fn neighbours26(&self) -> Vec<Self> {
+        let steps = [-T::ONE, T::ZERO, T::ONE];
+        let mut result = Vec::with_capacity(26);
+        for dx in steps {
+            for dy in steps {
+                for dz in steps {
+                    if (dx, dy, dz) != (T::ZERO, T::ZERO, T::ZERO) {
+                        result.push(*self + Point3D::create(dx, dy, dz));
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    pub fn manhatten_distance(&self, other: &Self) -> usize {
+        self.x.distance(other.x) + self.y.distance(other.y) + self.z.distance(other.z)
+    }
+
+    pub fn chebyshev_distance(&self, other: &Self) -> usize {
+        self.x.distance(other.x).max(self.y.distance(other.y)).max(self.z.distance(other.z))
+    }
+}
+
+impl<T: Coordinate> Add for Point3D<T> {
+    type Output = Point3D<T>;
+
+    fn add(self, rhs: Self) -> Point3D<T> {
+        Point3D::create(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Coordinate> Sub for Point3D<T> {
+    type Output = Point3D<T>;
+
+    fn sub(self, rhs: Self) -> Point3D<T> {
+        Point3D::create(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Coordinate> Mul<T> for Point3D<T> {
+    type Output = Point3D<T>;
+
+    fn mul(self, rhs: T) -> Point3D<T> {
+        Point3D::create(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T: Coordinate> Neg for Point3D<T> {
+    type Output = Point3D<T>;
+
+    fn neg(self) -> Point3D<T> {
+        Point3D::create(-self.x, -self.y, -self.z)
+    }
+}
+
+// like `1,2,3`
+impl<T: Coordinate> FromStr for Point3D<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match parse_coordinates(s)?[..] {
+            [x, y, z] => Ok(Point3D::create(x, y, z)),
+            _ => Err(Error::msg(format!("expected 3 coordinates, got '{s}'"))),
+        }
+    }
+}
+
+fn parse_coordinates<T: Coordinate>(s: &str) -> Result<Vec<T>> {
+    s.split(',')
+        .map(|v| v.trim().parse().map_err(|_| Error::msg(format!("invalid coordinate '{v}' in '{s}'"))))
+        .collect()
+}
+
+// The four directions on a map, the y-axis pointing down.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+// where something on a map looks at
+pub type Facing = Direction;
+
+impl Direction {
+    // clockwise, starting up
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    pub fn turn_left(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    pub fn turn_right(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn reverse(&self) -> Direction {
+        self.turn_left().turn_left()
+    }
+
+    // the step of one move
+    pub fn offset<T: Coordinate>(&self) -> Point2D<T> {
+        let (zero, one) = (T::ZERO, T::ONE);
+        match self {
+            Direction::Up => Point2D::create(zero, -one),
+            Direction::Right => Point2D::create(one, zero),
+            Direction::Down => Point2D::create(zero, one),
+            Direction::Left => Point2D::create(-one, zero),
+        }
+    }
+}
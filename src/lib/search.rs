@@ -0,0 +1,154 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+// A graph to search through, only described by its states and how they are connected.
+pub trait SearchSpace {
+    type State: Clone + Eq + Hash;
+
+    // The states reachable from `state`, with the cost of getting there.
+    fn neighbours(&self, state: &Self::State) -> Vec<(Self::State, usize)>;
+
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    // A lower bound of the remaining cost to a goal, only used by `astar`.
+    fn heuristic(&self, _state: &Self::State) -> usize {
+        0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found<T> {
+    // from the start to the goal, both included
+    pub path: Vec<T>,
+    pub cost: usize,
+}
+
+// Breadth-first, every step costs 1 regardless of the neighbour costs.
+pub fn bfs<S: SearchSpace>(space: &S, start: S::State) -> Option<Found<S::State>> {
+    bfs_from(space, vec![start])
+}
+
+pub fn bfs_from<S: SearchSpace>(space: &S, starts: Vec<S::State>) -> Option<Found<S::State>> {
+    let mut prevs: HashMap<S::State, Option<S::State>> = HashMap::new();
+    let mut q = VecDeque::new();
+    for start in starts {
+        if prevs.insert(start.clone(), None).is_none() {
+            q.push_back((start, 0));
+        }
+    }
+    while let Some((state, cost)) = q.pop_front() {
+        if space.is_goal(&state) {
+            return Some(Found { path: build_path(&prevs, state), cost });
+        }
+        for (next, _) in space.neighbours(&state) {
+            if !prevs.contains_key(&next) {
+                prevs.insert(next.clone(), Some(state.clone()));
+                q.push_back((next, cost + 1));
+            }
+        }
+    }
+    None
+}
+
+// The number of steps to every reachable state, the goal check is not used.
+pub fn distances<S: SearchSpace>(space: &S, start: S::State) -> HashMap<S::State, usize> {
+    let mut dists = HashMap::new();
+    let mut q = VecDeque::new();
+    dists.insert(start.clone(), 0);
+    q.push_back(start);
+    while let Some(state) = q.pop_front() {
+        let d = dists[&state];
+        for (next, _) in space.neighbours(&state) {
+            if !dists.contains_key(&next) {
+                dists.insert(next.clone(), d + 1);
+                q.push_back(next);
+            }
+        }
+    }
+    dists
+}
+
+pub fn dijkstra<S: SearchSpace>(space: &S, start: S::State) -> Option<Found<S::State>> {
+    best_first(space, vec![start], false)
+}
+
+pub fn dijkstra_from<S: SearchSpace>(space: &S, starts: Vec<S::State>) -> Option<Found<S::State>> {
+    best_first(space, starts, false)
+}
+
+pub fn astar<S: SearchSpace>(space: &S, start: S::State) -> Option<Found<S::State>> {
+    best_first(space, vec![start], true)
+}
+
+pub fn astar_from<S: SearchSpace>(space: &S, starts: Vec<S::State>) -> Option<Found<S::State>> {
+    best_first(space, starts, true)
+}
+
+fn best_first<S: SearchSpace>(space: &S, starts: Vec<S::State>, with_heuristic: bool) -> Option<Found<S::State>> {
+    let estimate = |state: &S::State| if with_heuristic { space.heuristic(state) } else { 0 };
+
+    let mut q = BinaryHeap::new();
+    let mut costs: HashMap<S::State, usize> = HashMap::new();
+    let mut prevs: HashMap<S::State, Option<S::State>> = HashMap::new();
+    for start in starts {
+        costs.insert(start.clone(), 0);
+        prevs.insert(start.clone(), None);
+        q.push(Item { priority: estimate(&start), cost: 0, state: start });
+    }
+
+    while let Some(Item { state, cost, .. }) = q.pop() {
+        if space.is_goal(&state) {
+            return Some(Found { path: build_path(&prevs, state), cost });
+        }
+        // already reached cheaper
+        if costs.get(&state).is_some_and(|&known| known < cost) {
+            continue;
+        }
+        for (next, step) in space.neighbours(&state) {
+            let alt = cost + step;
+            if costs.get(&next).is_none_or(|&known| alt < known) {
+                costs.insert(next.clone(), alt);
+                prevs.insert(next.clone(), Some(state.clone()));
+                q.push(Item { priority: alt + estimate(&next), cost: alt, state: next });
+            }
+        }
+    }
+    None
+}
+
+fn build_path<T: Clone + Eq + Hash>(prevs: &HashMap<T, Option<T>>, goal: T) -> Vec<T> {
+    let mut path = vec![goal];
+    while let Some(Some(prev)) = prevs.get(path.last().unwrap()) {
+        path.push(prev.clone());
+    }
+    path.reverse();
+    path
+}
+
+// ordered by the lowest priority first, for the max-heap
+struct Item<T> {
+    priority: usize,
+    cost: usize,
+    state: T,
+}
+
+impl<T> PartialEq for Item<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<T> Eq for Item<T> {}
+
+impl<T> PartialOrd for Item<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Item<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
@@ -0,0 +1,38 @@
+use crate::lib::common::PuzzleScope;
+
+pub fn write_header(scope: &PuzzleScope) {
+    println!();
+    println!("Advent Of Code {y}: Day {d} Part {p}", y = scope.year(), d = scope.day(), p = scope.part());
+}
+
+pub fn write_solution(scope: &PuzzleScope, str: &str) {
+    println!("The solution of Part {p} is: {s}", p = scope.part(), s = str);
+}
+
+pub fn write_table(headers: &[&str], rows: &[Vec<String>]) {
+    let widths: Vec<usize> = (0..headers.len())
+        .map(|i| {
+            rows.iter()
+                .map(|row| row[i].chars().count())
+                .chain([headers[i].len()])
+                .max()
+                .unwrap()
+        })
+        .collect();
+    let format_row = |cells: Vec<&str>| -> String {
+        cells.iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<String>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+
+    println!();
+    println!("{}", format_row(headers.to_vec()));
+    println!("{}", widths.iter().map(|w| "-".repeat(*w)).collect::<Vec<String>>().join("-+-"));
+    for row in rows {
+        println!("{}", format_row(row.iter().map(|c| c.as_str()).collect()));
+    }
+}
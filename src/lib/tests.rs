@@ -0,0 +1,12 @@
+mod answers;
+mod beam_search;
+mod bench;
+mod common;
+mod cycle;
+mod grid;
+mod image;
+mod interval;
+mod io;
+mod ocr;
+mod point;
+mod search;
\ No newline at end of file
@@ -0,0 +1,11 @@
+use crate::lib::answers::Answers;
+
+#[test]
+fn answers_test() {
+    let answers = Answers::parse("# part puzzle answer\n1 puzzle1 70613\n\n2 puzzle0 TGWSMRBPN\n").unwrap();
+    assert_eq!(answers.expected(1, "puzzle1"), Some("70613"));
+    assert_eq!(answers.expected(2, "puzzle0"), Some("TGWSMRBPN"));
+    assert_eq!(answers.expected(2, "puzzle1"), None);
+    assert!(Answers::parse("x puzzle1 1").is_err());
+    assert!(Answers::parse("1 puzzle1").is_err());
+}
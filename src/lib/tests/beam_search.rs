@@ -0,0 +1,49 @@
+use crate::lib::beam_search::{beam_search, BeamSearch, Node};
+
+// picks 1 or 2 on each of `left` levels, reaching the same sum in different orders
+#[derive(Clone, Debug)]
+struct Sum {
+    left: usize,
+    sum: usize,
+}
+
+impl Node for Sum {
+    type Key = (usize, usize);
+
+    fn children(&self) -> Vec<Self> {
+        if self.left == 0 {
+            return vec!();
+        }
+        (1..=2).map(|v| Sum { left: self.left - 1, sum: self.sum + v }).collect()
+    }
+
+    fn score(&self) -> usize {
+        self.sum
+    }
+
+    fn real_score(&self) -> usize {
+        self.sum
+    }
+
+    fn key(&self) -> Option<(usize, usize)> {
+        Some((self.left, self.sum))
+    }
+
+    fn upper_bound(&self) -> Option<usize> {
+        Some(self.sum + 2 * self.left)
+    }
+}
+
+#[test]
+fn beam_search_test() {
+    let outcome = beam_search(Sum { left: 5, sum: 0 }, 2);
+    assert_eq!(outcome.score(), 10);
+    assert_eq!(outcome.best.left, 0);
+    assert_eq!(outcome.stats.depth, 5);
+    assert!(outcome.stats.duplicates > 0);
+    assert!(outcome.stats.pruned > 0);
+
+    let parallel = BeamSearch::create(2).parallel(true).run(Sum { left: 5, sum: 0 });
+    assert_eq!(parallel.score(), 10);
+    assert_eq!(parallel.stats, outcome.stats);
+}
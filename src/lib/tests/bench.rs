@@ -0,0 +1,23 @@
+use std::time::Duration;
+
+use crate::lib::bench::{Report, Stats};
+
+#[test]
+fn stats_test() {
+    let samples: Vec<Duration> = [4, 2, 8, 6].iter().map(|&n| Duration::from_nanos(n)).collect();
+    let stats = Stats::create(2022, 1, 2, &samples);
+    assert_eq!(stats.runs, 4);
+    assert_eq!(stats.min, 2.0);
+    assert_eq!(stats.median, 5.0);
+    assert_eq!(stats.mean, 5.0);
+    assert_eq!(stats.stddev, 5f64.sqrt());
+}
+
+#[test]
+fn report_test() {
+    let report = Report { results: vec!(Stats::create(2022, 3, 1, &[Duration::from_micros(7)])) };
+    let json = serde_json::to_string(&report).unwrap();
+    let loaded: Report = serde_json::from_str(json.as_str()).unwrap();
+    assert_eq!(loaded.find(2022, 3, 1).unwrap().median, 7_000.0);
+    assert!(loaded.find(2022, 3, 2).is_none());
+}
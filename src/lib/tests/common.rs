@@ -0,0 +1,14 @@
+use std::collections::HashMap;
+
+use crate::lib::common::{PuzzleScope, Variant};
+
+#[test]
+fn scope_params_test() {
+    let scope = PuzzleScope::create(2022, 15, 1)
+        .with_variant(Variant::Example)
+        .with_params(HashMap::from([("row".to_string(), "10".to_string())]));
+    assert_eq!(scope.puzzle(), "puzzle0");
+    assert_eq!(scope.param::<i32>("row").unwrap(), 10);
+    assert!(scope.param::<i32>("limit").is_err());
+    assert_eq!(PuzzleScope::create(2022, 15, 1).puzzle(), "puzzle1");
+}
@@ -0,0 +1,49 @@
+use crate::lib::cycle;
+use crate::lib::cycle::Cycle;
+
+// 3, 10, 101, 2, 5, 26, 167, 95, 101, ... repeats from step 2 on every 6 steps
+fn next(x: &u32) -> u32 {
+    (x * x + 1) % 255
+}
+
+#[test]
+fn brent_floyd_test() {
+    let expected = Cycle { start: 2, length: 6 };
+    assert_eq!(cycle::brent(3, next), expected);
+    assert_eq!(cycle::floyd(3, next), expected);
+    assert_eq!(cycle::brent(0, |x| (x + 1) % 7), Cycle { start: 0, length: 7 });
+    assert_eq!(expected.equivalent(1), 1);
+    assert_eq!(expected.equivalent(14), 2);
+    assert_eq!(expected.equivalent(1_000_000), 4);
+}
+
+#[test]
+fn find_by_key_test() {
+    let mut state = 3;
+    let found = cycle::find_by_key(&mut state, 100, |x| *x = next(x), |x| *x);
+    assert_eq!(found, Some(Cycle { start: 2, length: 6 }));
+    assert_eq!(state, 101);
+
+    let mut state = 0u64;
+    assert_eq!(cycle::find_by_key(&mut state, 10, |x| *x += 1, |x| *x), None);
+    assert_eq!(state, 10);
+}
+
+#[test]
+fn extrapolate_test() {
+    // (position on a ring of 4, distance walked): 1, 2, 3, 4 steps per turn
+    let simulate = |n| cycle::extrapolate(
+        (0usize, 0i64),
+        n,
+        |(pos, walked)| {
+            *walked += *pos as i64 + 1;
+            *pos = (*pos + 1) % 4;
+        },
+        |(pos, _)| *pos,
+        |(_, walked)| *walked,
+    );
+    assert_eq!(simulate(3), 6);
+    assert_eq!(simulate(4), 10);
+    assert_eq!(simulate(7), 16);
+    assert_eq!(simulate(1_000_000_000_001), 2_500_000_000_001);
+}
@@ -0,0 +1,46 @@
+use crate::lib::grid::{Grid2D, SparseGrid};
+use crate::lib::point::Point2D;
+
+#[test]
+fn grid_test() {
+    let grid = Grid2D::create(10, 10, 0);
+    assert_eq!(grid.size(), 100, "invalid size");
+}
+
+#[test]
+fn grid_parse_test() {
+    let mut grid = Grid2D::parse("123\n456\n", |c| c.to_digit(10)).unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.get(2, 1), Some(&6));
+    assert_eq!(grid.get(3, 1), None);
+    assert_eq!(grid.set(0, 0, 9), Some(1));
+    assert_eq!(grid.set(-1, 0, 9), None);
+
+    assert_eq!(grid.rows().map(|r| r.iter().sum::<u32>()).collect::<Vec<_>>(), vec!(14, 15));
+    assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec!(2, 5));
+    assert_eq!(grid.neighbours(0, 0).map(|(_, v)| *v).collect::<Vec<_>>(), vec!(2, 4));
+    assert_eq!(grid.ray(0, 1, (1, 0)).map(|(_, v)| *v).collect::<Vec<_>>(), vec!(5, 6));
+    assert_eq!(grid.to_string(), "923\n456\n");
+
+    assert!(Grid2D::parse("12\n3\n", |c| c.to_digit(10)).is_err());
+    let err = Grid2D::parse("12\n3x\n", |c| c.to_digit(10)).unwrap_err();
+    assert_eq!(err.to_string(), "2:2: unexpected 'x'");
+}
+
+#[test]
+fn sparse_grid_test() {
+    let mut grid: SparseGrid<char> = SparseGrid::new();
+    assert_eq!(grid.bounds(), None);
+
+    grid.insert(Point2D::create(0, 0), 'a');
+    grid.insert(Point2D::create(2, -1), 'b');
+    grid.insert(Point2D::create(2, 1), 'c');
+    assert_eq!(grid.bounds(), Some((Point2D::create(0, -1), Point2D::create(2, 1))));
+    assert_eq!(grid.count_empty(), 6);
+    assert_eq!(grid.render(|c| *c.unwrap_or(&'.')), "..b\na..\n..c\n");
+
+    grid.remove(&Point2D::create(2, -1));
+    grid.remove(&Point2D::create(2, 1));
+    assert_eq!(grid.bounds(), Some((Point2D::create(0, 0), Point2D::create(0, 0))));
+    assert_eq!(grid.count_region(Point2D::create(-1, -1), Point2D::create(1, 1), |c| c.is_none()), 8);
+}
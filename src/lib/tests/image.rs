@@ -0,0 +1,40 @@
+use std::env;
+use std::fs;
+
+use crate::lib::grid::Grid2D;
+use crate::lib::image::{BLACK, FrameRecorder, Image, WHITE};
+
+#[test]
+fn image_ppm_test() {
+    let grid = Grid2D::parse("#.\n.#\n", |c| Some(c == '#')).unwrap();
+    let image = Image::from_grid(&grid, |&v| if v { WHITE } else { BLACK });
+    assert_eq!(image.get(1, 1), Some(WHITE));
+    assert_eq!(image.scale(2).get(3, 2), Some(WHITE));
+
+    let mut out = vec!();
+    image.write_ppm(&mut out).unwrap();
+    assert_eq!(&out[..11], b"P6\n2 2\n255\n");
+    assert_eq!(&out[11..], &[255, 255, 255, 0, 0, 0, 0, 0, 0, 255, 255, 255]);
+
+    let mut out = vec!();
+    image.write_png(&mut out).unwrap();
+    assert_eq!(&out[..8], b"\x89PNG\r\n\x1a\n");
+}
+
+#[test]
+fn frame_recorder_test() {
+    let dir = env::temp_dir().join("aoc_frame_recorder_test");
+    let _ = fs::remove_dir_all(&dir);
+
+    let mut disabled = FrameRecorder::create(None, "test").unwrap();
+    disabled.record(|| unreachable!()).unwrap();
+    assert_eq!(disabled.frames(), 0);
+
+    let mut recorder = FrameRecorder::create(Some(dir.clone()), "test").unwrap().every(2);
+    for _ in 0..5 {
+        recorder.record(|| Image::create(1, 1, WHITE)).unwrap();
+    }
+    assert_eq!(recorder.frames(), 3);
+    assert!(dir.join("test-00003.png").exists());
+    fs::remove_dir_all(&dir).unwrap();
+}
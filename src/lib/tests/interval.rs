@@ -0,0 +1,62 @@
+use crate::lib::interval::IntervalSet;
+
+#[test]
+fn insert_test() {
+    let mut set: IntervalSet<i32> = [5..=7, 1..=2, 10..=12].into_iter().collect();
+    assert_eq!(set.ranges().collect::<Vec<_>>(), vec!(1..=2, 5..=7, 10..=12));
+    assert_eq!(set.len(), 8);
+
+    // adjacent ranges are merged
+    set.insert(3..=4);
+    assert_eq!(set.ranges().collect::<Vec<_>>(), vec!(1..=7, 10..=12));
+    set.insert(6..=11);
+    assert_eq!(set.ranges().collect::<Vec<_>>(), vec!(1..=12));
+
+    #[allow(clippy::reversed_empty_ranges)]
+    set.insert(20..=19);
+    assert_eq!(set.span(), Some(1..=12));
+    assert!(set.contains(&12) && !set.contains(&13));
+}
+
+#[test]
+fn remove_test() {
+    let mut set = IntervalSet::from(1..=10);
+    set.remove(4..=5);
+    set.remove(10..=20);
+    assert_eq!(set.ranges().collect::<Vec<_>>(), vec!(1..=3, 6..=9));
+    assert_eq!(set.gaps().collect::<Vec<_>>(), vec!(4..=5));
+    set.remove(0..=100);
+    assert!(set.is_empty());
+    assert_eq!(set.len(), 0);
+}
+
+#[test]
+fn set_operations_test() {
+    let a: IntervalSet<i32> = [1..=5, 10..=15].into_iter().collect();
+    let b: IntervalSet<i32> = [4..=11, 20..=20].into_iter().collect();
+    assert_eq!(a.union(&b).ranges().collect::<Vec<_>>(), vec!(1..=15, 20..=20));
+    assert_eq!(a.intersection(&b).ranges().collect::<Vec<_>>(), vec!(4..=5, 10..=11));
+    assert_eq!(a.complement(0..=12).ranges().collect::<Vec<_>>(), vec!(0..=0, 6..=9));
+
+    assert!(a.contains_range(&(2..=4)));
+    assert!(!a.contains_range(&(4..=10)));
+    assert!(a.overlaps(&(5..=9)));
+    assert!(!a.overlaps(&(6..=9)));
+}
+
+#[test]
+fn overflow_test() {
+    let mut set = IntervalSet::from(i64::MIN..=i64::MAX);
+    assert_eq!(set.len(), 1u128 << 64);
+    set.remove(i64::MIN..=i64::MIN);
+    set.remove(i64::MAX..=i64::MAX);
+    assert_eq!(set.span(), Some(i64::MIN + 1..=i64::MAX - 1));
+    set.insert(i64::MAX..=i64::MAX);
+    set.insert(i64::MIN..=i64::MIN);
+    assert_eq!(set.ranges().count(), 1);
+    assert!(set.complement(i64::MIN..=i64::MAX).is_empty());
+
+    let edges: IntervalSet<u64> = [0..=0, u64::MAX..=u64::MAX].into_iter().collect();
+    assert_eq!(edges.gaps().collect::<Vec<_>>(), vec!(1..=u64::MAX - 1));
+    assert_eq!(edges.complement(0..=u64::MAX).len(), u64::MAX as u128 - 1);
+}
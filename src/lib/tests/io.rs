@@ -0,0 +1,95 @@
+use std::env;
+use std::fs;
+
+use crate::lib::common::{InputSource, PuzzleScope};
+use crate::lib::io;
+use crate::lib::point::Point2D;
+
+#[test]
+fn read_puzzle_as_ints_test() {
+    let path = env::temp_dir().join("aoc_io_test_ints.txt");
+    let scope = PuzzleScope::create(2022, 0, 1).with_source(InputSource::File(path.clone()));
+
+    fs::write(&path, "1\n2\n").unwrap();
+    assert_eq!(io::read_puzzle_as_ints(&scope, "puzzle1").unwrap(), vec!(1, 2));
+
+    fs::write(&path, "1\nx\n3").unwrap();
+    let err = io::read_puzzle_as_ints(&scope, "puzzle1").unwrap_err();
+    assert!(format!("{:#}", err).contains(":2: cannot parse 'x'"), "{:#}", err);
+
+    fs::remove_file(&path).unwrap();
+    assert!(io::read_puzzle_as_string(&scope, "puzzle1").is_err());
+}
+
+#[test]
+fn input_source_test() {
+    assert_eq!("-".parse::<InputSource>().unwrap(), InputSource::Stdin);
+    assert_eq!("a/b.txt".parse::<InputSource>().unwrap(), InputSource::File("a/b.txt".into()));
+    assert!("".parse::<InputSource>().is_err());
+}
+
+#[test]
+fn ints_in_test() {
+    assert_eq!(io::ints_in::<i32>("Sensor at x=2, y=-18: beacon at x=-2, y=15").unwrap(), vec!(2, -18, -2, 15));
+    assert_eq!(io::ints_in::<i32>("5-7,7-9").unwrap(), vec!(5, 7, 7, 9));
+    assert!(io::ints_in::<i32>("no numbers").unwrap().is_empty());
+
+    let err = io::ints_in::<u8>("a 1 b 300").unwrap_err();
+    assert!(format!("{:#}", err).starts_with("7: cannot parse '300'"), "{:#}", err);
+}
+
+#[test]
+fn read_puzzle_as_test() {
+    let path = env::temp_dir().join("aoc_io_test_as.txt");
+    let scope = PuzzleScope::create(2022, 0, 1).with_source(InputSource::File(path.clone()));
+
+    fs::write(&path, "1,2\n\n3,4\n").unwrap();
+    let points: Vec<Point2D> = io::read_puzzle_as(&scope, "puzzle1").unwrap();
+    assert_eq!(points, vec!(Point2D::create(1, 2), Point2D::create(3, 4)));
+
+    fs::write(&path, "1,2\n  3,x\n").unwrap();
+    let err = io::read_puzzle_as::<Point2D>(&scope, "puzzle1").unwrap_err();
+    assert!(format!("{:#}", err).contains(":2:3: cannot parse '  3,x'"), "{:#}", err);
+
+    fs::write(&path, "a 1\nb -2 3\n\nc\n").unwrap();
+    let ints: Vec<Vec<i64>> = io::read_puzzle_as_int_lines(&scope, "puzzle1").unwrap();
+    assert_eq!(ints, vec!(vec!(1), vec!(-2, 3), vec!(), vec!()));
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn read_puzzle_as_blocks_test() {
+    let path = env::temp_dir().join("aoc_io_test_blocks.txt");
+    let scope = PuzzleScope::create(2022, 0, 1).with_source(InputSource::File(path.clone()));
+
+    fs::write(&path, "1\n2\n\n\n3\r\n\r\n4\n").unwrap();
+    assert_eq!(io::read_puzzle_as_blocks(&scope, "puzzle1").unwrap(), vec!(vec!("1", "2"), vec!("3"), vec!("4")));
+
+    fs::write(&path, "1,2\n\n3,4,5\n6,7\n").unwrap();
+    let err = io::read_puzzle_as_blocks_of::<Point2D>(&scope, "puzzle1").unwrap_err();
+    assert!(format!("{:#}", err).contains(":3:1: cannot parse block"), "{:#}", err);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn read_puzzle_as_grid_test() {
+    let path = env::temp_dir().join("aoc_io_test_grid.txt");
+    let scope = PuzzleScope::create(2022, 0, 1).with_source(InputSource::File(path.clone()));
+
+    fs::write(&path, "#.\n.#\n").unwrap();
+    let grid = io::read_puzzle_as_char_grid(&scope, "puzzle1").unwrap();
+    assert_eq!((grid.width(), grid.height()), (2, 2));
+    assert_eq!(grid.get(1, 1), Some(&'#'));
+
+    fs::write(&path, "12\n3x\n").unwrap();
+    let err = io::read_puzzle_as_grid(&scope, "puzzle1", |c| c.to_digit(10)).unwrap_err();
+    assert!(format!("{:#}", err).ends_with("aoc_io_test_grid.txt:2:2: unexpected 'x'"), "{:#}", err);
+
+    fs::write(&path, "12\n345\n").unwrap();
+    let err = io::read_puzzle_as_char_grid(&scope, "puzzle1").unwrap_err();
+    assert!(format!("{:#}", err).ends_with(":2:3: expected 2 cells, got 3"), "{:#}", err);
+
+    fs::remove_file(&path).unwrap();
+}
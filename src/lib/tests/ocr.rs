@@ -0,0 +1,38 @@
+use crate::lib::grid::Grid2D;
+use crate::lib::ocr::{parse_screen, recognize};
+
+#[test]
+fn recognize_4x6_test() {
+    // the last column is noise of the day 10 CRT
+    let screen = parse_screen("\
+###...##..###....##..##..###..#..#.###..
+#..#.#..#.#..#....#.#..#.#..#.#..#.#..##
+#..#.#..#.#..#....#.#....###..####.#..##
+###..####.###.....#.#....#..#.#..#.###..
+#....#..#.#....#..#.#..#.#..#.#..#.#....
+#....#..#.#.....##...##..###..#..#.#....
+").unwrap();
+    assert_eq!(recognize(&screen).unwrap(), "PAPJCBHP");
+
+    let err = recognize(&parse_screen("#.#.\n.#.#\n#.#.\n.#.#\n#.#.\n.#.#\n").unwrap()).unwrap_err();
+    assert!(err.to_string().starts_with("unknown letter #1"), "{}", err);
+    assert!(recognize(&Grid2D::create(3, 10, false)).is_err());
+}
+
+#[test]
+fn recognize_6x10_test() {
+    // a message in the style of the 2018 day 10 sky
+    let screen = parse_screen("\
+.####......###..#....#..#....#..#....#..######..#####...#####.
+#....#......#...#...#...#....#..##...#.......#..#....#..#....#
+#...........#...#..#.....#..#...##...#.......#..#....#..#....#
+#...........#...#.#......#..#...#.#..#......#...#....#..#....#
+#...........#...##........##....#.#..#.....#....#####...#####.
+#..###......#...##........##....#..#.#....#.....#..#....#....#
+#....#......#...#.#......#..#...#..#.#...#......#...#...#....#
+#....#..#...#...#..#.....#..#...#...##..#.......#...#...#....#
+#...##..#...#...#...#...#....#..#...##..#.......#....#..#....#
+.###.#...###....#....#..#....#..#....#..######..#....#..#####.
+").unwrap();
+    assert_eq!(recognize(&screen).unwrap(), "GJKXNZRB");
+}
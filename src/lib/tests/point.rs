@@ -0,0 +1,37 @@
+use crate::lib::point::{Direction, Facing, Point2D, Point3D};
+
+#[test]
+fn point2d_test() {
+    let a: Point2D<i64> = "3,-4".parse().unwrap();
+    let b = Point2D::create(1, 2);
+    assert_eq!(a + b, Point2D::create(4, -2));
+    assert_eq!(a - b, Point2D::create(2, -6));
+    assert_eq!(-a * 2, Point2D::create(-6, 8));
+    assert_eq!(a.manhatten_distance(&b), 8);
+    assert_eq!(a.chebyshev_distance(&b), 6);
+    assert_eq!(Point2D::create(1, 0).rotate_right(), Point2D::create(0, 1));
+    assert_eq!(Point2D::create(1, 0).rotate_left(), Point2D::create(0, -1));
+    assert_eq!(b.to_string(), "(1/2)");
+    assert!("1,2,3".parse::<Point2D>().is_err());
+}
+
+#[test]
+fn point3d_test() {
+    let p: Point3D = "1,2,3".parse().unwrap();
+    assert_eq!(p.neighbours6().len(), 6);
+    assert!(p.neighbours6().iter().all(|n| n.manhatten_distance(&p) == 1));
+    assert_eq!(p.neighbours26().len(), 26);
+    assert!(p.neighbours26().iter().all(|n| n.chebyshev_distance(&p) == 1));
+    assert_eq!(p - Point3D::create(1, 1, 1), Point3D::create(0, 1, 2));
+}
+
+#[test]
+fn direction_test() {
+    let facing: Facing = Direction::Up;
+    assert_eq!(facing.turn_right(), Direction::Right);
+    assert_eq!(facing.turn_left(), Direction::Left);
+    assert_eq!(facing.reverse(), Direction::Down);
+    for d in Direction::ALL {
+        assert_eq!(d.turn_right().offset::<i32>(), d.offset().rotate_right());
+    }
+}
@@ -0,0 +1,56 @@
+use crate::lib::grid::Grid2D;
+use crate::lib::point::Point2D;
+use crate::lib::search::{astar, bfs, dijkstra, distances, SearchSpace};
+
+// walls are `#`, digits are the cost of entering a cell
+struct Maze {
+    grid: Grid2D<char>,
+    goal: Point2D,
+}
+
+impl SearchSpace for Maze {
+    type State = Point2D;
+
+    fn neighbours(&self, p: &Point2D) -> Vec<(Point2D, usize)> {
+        self.grid.neighbours(p.x(), p.y())
+            .filter(|(_, &c)| c != '#')
+            .map(|(n, &c)| (n, c.to_digit(10).unwrap_or(1) as usize))
+            .collect()
+    }
+
+    fn is_goal(&self, p: &Point2D) -> bool {
+        p == &self.goal
+    }
+
+    fn heuristic(&self, p: &Point2D) -> usize {
+        p.manhatten_distance(&self.goal)
+    }
+}
+
+#[test]
+fn search_test() {
+    let maze = Maze {
+        grid: Grid2D::parse_chars("\
+.9..
+.#.#
+....
+").unwrap(),
+        goal: Point2D::create(3, 0),
+    };
+    let start = Point2D::create(0, 0);
+
+    let found = bfs(&maze, start).unwrap();
+    assert_eq!(found.cost, 3);
+    assert_eq!(found.path.first(), Some(&start));
+    assert_eq!(found.path.last(), Some(&maze.goal));
+
+    // around the expensive cell
+    assert_eq!(dijkstra(&maze, start).unwrap().cost, 7);
+    let found = astar(&maze, start).unwrap();
+    assert_eq!(found.cost, 7);
+    assert_eq!(found.path.len(), 8);
+
+    assert_eq!(distances(&maze, start).len(), 10);
+    let unreachable = Maze { grid: maze.grid.clone(), goal: Point2D::create(3, 1) };
+    assert_eq!(astar(&unreachable, start), None);
+}
@@ -1,82 +1,247 @@
+// the shared `lib` module is no library target
+#![allow(special_module_name)]
+
 extern crate core;
 
-use crate::aoc2022::hive::{Day, Hive, Part};
-use crate::aoc2022::lib::common::{parse_int, PuzzleScope};
+use std::panic;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use clap::Parser;
+
+use crate::cli::{BenchArgs, Cli, Command, Filter, RunAllArgs};
+use crate::hive::Hive;
+use crate::lib::answers::{verify, Verdict};
+use crate::lib::bench::{Report, Stats};
+use crate::lib::io;
+use crate::lib::common::{Answer, format_duration, measure_time, PuzzleScope, Variant};
+use crate::lib::style::{write_header, write_solution, write_table};
 
 mod aoc2022;
+//GEN_YEAR_MOD
+mod cli;
+mod hive;
+// `lib/mod.rs` instead of `lib.rs`, cargo would take that one for a library crate
+mod lib;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
 
-fn main() {
     let mut hive = Hive::create();
-    hive.register(Day::D00, Part::P01, aoc2022::day00::part1::run);
-    hive.register(Day::D00, Part::P02, aoc2022::day00::part2::run);
-    hive.register(Day::D01, Part::P01, aoc2022::day01::part1::run);
-    hive.register(Day::D01, Part::P02, aoc2022::day01::part2::run);
-    hive.register(Day::D02, Part::P01, aoc2022::day02::part1::run);
-    hive.register(Day::D02, Part::P02, aoc2022::day02::part2::run);
-    hive.register(Day::D03, Part::P01, aoc2022::day03::part1::run);
-    hive.register(Day::D03, Part::P02, aoc2022::day03::part2::run);
-    hive.register(Day::D04, Part::P01, aoc2022::day04::part1::run);
-    hive.register(Day::D04, Part::P02, aoc2022::day04::part2::run);
-    hive.register(Day::D05, Part::P01, aoc2022::day05::part1::run);
-    hive.register(Day::D05, Part::P02, aoc2022::day05::part2::run);
-    hive.register(Day::D06, Part::P01, aoc2022::day06::part1::run);
-    hive.register(Day::D06, Part::P02, aoc2022::day06::part2::run);
-    hive.register(Day::D07, Part::P01, aoc2022::day07::part1::run);
-    hive.register(Day::D07, Part::P02, aoc2022::day07::part2::run);
-    hive.register(Day::D08, Part::P01, aoc2022::day08::part1::run);
-    hive.register(Day::D08, Part::P02, aoc2022::day08::part2::run);
-    hive.register(Day::D09, Part::P01, aoc2022::day09::part1::run);
-    hive.register(Day::D09, Part::P02, aoc2022::day09::part2::run);
-    hive.register(Day::D10, Part::P01, aoc2022::day10::part1::run);
-    hive.register(Day::D10, Part::P02, aoc2022::day10::part2::run);
-    hive.register(Day::D11, Part::P01, aoc2022::day11::part1::run);
-    hive.register(Day::D11, Part::P02, aoc2022::day11::part2::run);
-    hive.register(Day::D12, Part::P01, aoc2022::day12::part1::run);
-    hive.register(Day::D12, Part::P02, aoc2022::day12::part2::run);
-    hive.register(Day::D13, Part::P01, aoc2022::day13::part1::run);
-    hive.register(Day::D13, Part::P02, aoc2022::day13::part2::run);
-    hive.register(Day::D14, Part::P01, aoc2022::day14::part1::run);
-    hive.register(Day::D14, Part::P02, aoc2022::day14::part2::run);
-    hive.register(Day::D15, Part::P01, aoc2022::day15::part1::run);
-    hive.register(Day::D15, Part::P02, aoc2022::day15::part2::run);
-    hive.register(Day::D16, Part::P01, aoc2022::day16::part1::run);
-    hive.register(Day::D16, Part::P02, aoc2022::day16::part2::run);
-    hive.register(Day::D17, Part::P01, aoc2022::day17::part1::run);
-    hive.register(Day::D17, Part::P02, aoc2022::day17::part2::run);
-    hive.register(Day::D18, Part::P01, aoc2022::day18::part1::run);
-    hive.register(Day::D18, Part::P02, aoc2022::day18::part2::run);
-    hive.register(Day::D19, Part::P01, aoc2022::day19::part1::run);
-    hive.register(Day::D19, Part::P02, aoc2022::day19::part2::run);
-    hive.register(Day::D20, Part::P01, aoc2022::day20::part1::run);
-    hive.register(Day::D20, Part::P02, aoc2022::day20::part2::run);
-    hive.register(Day::D21, Part::P01, aoc2022::day21::part1::run);
-    hive.register(Day::D21, Part::P02, aoc2022::day21::part2::run);
-    hive.register(Day::D22, Part::P01, aoc2022::day22::part1::run);
-    hive.register(Day::D22, Part::P02, aoc2022::day22::part2::run);
-    hive.register(Day::D23, Part::P01, aoc2022::day23::part1::run);
-    hive.register(Day::D23, Part::P02, aoc2022::day23::part2::run);
-    hive.register(Day::D24, Part::P01, aoc2022::day24::part1::run);
-    hive.register(Day::D24, Part::P02, aoc2022::day24::part2::run);
-    hive.register(Day::D25, Part::P01, aoc2022::day25::part1::run);
-    //GEN_HIVE_REGISTER
-
-    let day = format!("d{:02}", parse_int(std::env::var("DAY").unwrap_or("1".to_string()).as_str()));
-    let part = format!("p{:02}", parse_int(std::env::var("PART").unwrap_or("1".to_string()).as_str()));
-
-    //println!("Dispatching {day} / {part}", day = day.clone(), part = part.clone());
-    dispatch(&hive, day, part);
+    aoc2022::register(&mut hive);
+    //GEN_YEAR_REGISTER
+
+    let exit_code = match cli.command {
+        Command::Run(filter) => run(&hive, &filter),
+        Command::RunAll(args) => run_all(&hive, &args),
+        Command::List => list(&hive),
+        Command::Bench(args) => bench(&hive, &args),
+    };
+
+    println!();
+    println!("kthxbye!");
+    exit_code
+}
+
+fn select(hive: &Hive, filter: &Filter) -> Vec<(i32, i32, i32)> {
+    hive.entries()
+        .into_iter()
+        .filter(|(year, day, part)| filter.matches(*year, *day, *part))
+        .collect()
+}
+
+fn run(hive: &Hive, filter: &Filter) -> ExitCode {
+    let selected = select(hive, filter);
+    if selected.is_empty() {
+        println!("No puzzle matches the selection");
+        return ExitCode::FAILURE;
+    }
+    let mut failed = false;
+    for (year, day, part) in selected {
+        let scope = PuzzleScope::create(year, day, part)
+            .with_source(filter.input.clone().unwrap_or_default())
+            .with_variant(filter.variant())
+            .with_frames(filter.frames.clone(), filter.frame_step);
+        match dispatch(hive, scope) {
+            Ok((_, Verdict::Wrong(_))) | Err(_) => failed = true,
+            Ok(_) => {}
+        }
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+// runs every puzzle, a failing or panicking one is reported and does not stop the others
+fn run_all(hive: &Hive, args: &RunAllArgs) -> ExitCode {
+    let variant = if args.example { Variant::Example } else { Variant::Real };
+    let mut rows: Vec<Vec<String>> = vec!();
+    let mut failures = 0;
+    for (year, day, part) in hive.entries() {
+        let now = Instant::now();
+        let scope = PuzzleScope::create(year, day, part)
+            .with_variant(variant);
+        let result = panic::catch_unwind(|| dispatch(hive, scope));
+        let elapsed = now.elapsed();
+        let (answer, status) = match result {
+            Ok(Ok((answer, Verdict::Correct))) => (answer.to_string(), "ok".to_string()),
+            Ok(Ok((answer, Verdict::Unknown))) => (answer.to_string(), "unchecked".to_string()),
+            Ok(Ok((answer, Verdict::Wrong(expected)))) => (answer.to_string(), format!("WRONG (expected {expected})")),
+            Ok(Err(err)) => (format!("{err:#}"), "FAILED".to_string()),
+            Err(_) => ("panicked".to_string(), "FAILED".to_string()),
+        };
+        if status != "ok" && status != "unchecked" {
+            failures += 1;
+        }
+        rows.push(vec![
+            year.to_string(),
+            format!("{:02}", day),
+            part.to_string(),
+            summarize_answer(&answer),
+            format_duration(elapsed.as_nanos() as f64),
+            status,
+        ]);
+    }
 
+    write_table(&["Year", "Day", "Part", "Answer", "Elapsed", "Status"], &rows);
     println!();
-    println!("kthxbye!")
+    println!("{} of {} puzzles passed", rows.len() - failures, rows.len());
+    if failures > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+// keeps the table in shape, a multi-line answer (like a rendered screen) is only hinted
+fn summarize_answer(answer: &str) -> String {
+    let answer = answer.trim();
+    if answer.contains('\n') {
+        "(multi-line)".to_string()
+    } else {
+        answer.to_string()
+    }
+}
+
+fn list(hive: &Hive) -> ExitCode {
+    for (year, day, part) in hive.entries() {
+        println!("{} Day {:02} Part {}", year, day, part);
+    }
+    ExitCode::SUCCESS
+}
+
+// the solutions run without the usual output of the runner, only the first run is checked
+fn bench(hive: &Hive, args: &BenchArgs) -> ExitCode {
+    let baseline = match &args.compare {
+        Some(path) => match Report::load(path) {
+            Ok(report) => Some(report),
+            Err(err) => {
+                println!("Invalid baseline: {err:#}");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => None,
+    };
+
+    let mut results: Vec<Stats> = vec!();
+    for (year, day, part) in select(hive, &args.filter) {
+        let scope = PuzzleScope::create(year, day, part)
+            .with_source(args.filter.input.clone().unwrap_or_default())
+            .with_variant(args.filter.variant());
+        if let Err(err) = dispatch(hive, scope.clone()) {
+            println!("Skipping {} Day {:02} Part {}: {err:#}", year, day, part);
+            continue;
+        }
+        let cb = hive.lookup(year, day, part)
+            .expect("invalid day");
+        let scope = match prepare(scope) {
+            Ok(scope) => scope,
+            Err(_) => continue,
+        };
+        for _ in 0..args.warmup {
+            let _ = cb(&scope);
+        }
+        let samples: Vec<Duration> = (0..args.runs)
+            .map(|_| measure_time(&scope, *cb).1)
+            .collect();
+        results.push(Stats::create(year, day, part, &samples));
+    }
+
+    let mut headers = vec!["Year", "Day", "Part", "Min", "Median", "Mean", "Stddev"];
+    if baseline.is_some() {
+        headers.extend(["Baseline", "Change"]);
+    }
+    let rows: Vec<Vec<String>> = results.iter()
+        .map(|stats| {
+            let mut row = vec![
+                stats.year.to_string(),
+                format!("{:02}", stats.day),
+                stats.part.to_string(),
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.mean),
+                format_duration(stats.stddev),
+            ];
+            if let Some(report) = &baseline {
+                match report.find(stats.year, stats.day, stats.part) {
+                    Some(base) => {
+                        row.push(format_duration(base.median));
+                        row.push(format!("{:+.1}%", (stats.median / base.median - 1.0) * 100.0));
+                    }
+                    None => row.extend(["-".to_string(), "-".to_string()]),
+                }
+            }
+            row
+        })
+        .collect();
+    println!();
+    println!("Timings of {} runs after {} warm-up runs (comparing medians)", args.runs, args.warmup);
+    write_table(&headers, &rows);
+
+    if let Some(path) = &args.save {
+        if let Err(err) = (Report { results }).save(path) {
+            println!("Cannot save results: {err:#}");
+            return ExitCode::FAILURE;
+        }
+        println!();
+        println!("Results saved to {}", path.display());
+    }
+    ExitCode::SUCCESS
+}
+
+// completes a scope with the parameters of its variant
+fn prepare(scope: PuzzleScope) -> Result<PuzzleScope> {
+    let params = io::read_params(&scope)?;
+    Ok(scope.with_params(params))
 }
 
-fn dispatch(hive: &Hive, day: String, part: String) {
-    let d = Day::from_str(day.clone())
-        .expect(format!("invalid day: {}", day).as_str());
-    let p = Part::from_str(part.clone())
-        .expect(format!("invalid part: {}", part).as_str());
-    let cb = hive.lookup(d, p)
+// runs a single puzzle and checks its answer against the recorded one
+fn dispatch(hive: &Hive, scope: PuzzleScope) -> Result<(Answer, Verdict)> {
+    let cb = hive.lookup(scope.year(), scope.day(), scope.part())
         .expect("invalid day");
-    let scope = PuzzleScope::create(2022, d.as_int(), p.as_int());
-    cb(scope);
+    write_header(&scope);
+    if scope.variant() == Variant::Example {
+        println!("(solving the example)");
+    }
+    let part = scope.part();
+    let scope = prepare(scope)
+        .inspect_err(|err| println!("Part {part} failed: {err:#}"))?;
+    let (result, elapsed) = measure_time(&scope, *cb);
+    match &result {
+        Ok(answer) => write_solution(&scope, answer.to_string().as_str()),
+        Err(err) => println!("Part {p} failed: {err:#}", p = scope.part()),
+    }
+    println!("Elapsed time: {}", format_duration(elapsed.as_nanos() as f64));
+    let answer = result?;
+    let verdict = verify(&scope, &answer)?;
+    match &verdict {
+        Verdict::Correct => println!("The answer matches the expected one"),
+        Verdict::Unknown if scope.variant() == Variant::Example => println!("There is no expected answer of the example"),
+        Verdict::Wrong(expected) => println!("WRONG ANSWER, expected: {expected}"),
+        Verdict::Unknown => {}
+    }
+    Ok((answer, verdict))
 }